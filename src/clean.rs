@@ -0,0 +1,192 @@
+use std::borrow::Cow;
+
+use crate::fold_to_ascii;
+
+/// Cleaning policy for [`clean_with_report`].
+///
+/// `CleanOptions::new()` cleans like [`clean`](crate::clean): every char that is not an ASCII
+/// letter or digit is removed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CleanOptions {
+    uppercase: bool,
+    unicode: bool,
+}
+
+impl CleanOptions {
+    /// Create options that clean like [`clean`](crate::clean)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Uppercase ASCII lowercase letters
+    pub fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Fold compatibility forms and lookalikes to ASCII first, like [`clean_unicode`](crate::clean_unicode)
+    pub fn unicode(mut self, unicode: bool) -> Self {
+        self.unicode = unicode;
+        self
+    }
+}
+
+/// A change made by [`clean_with_report`]. Positions are zero-based char indices into the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanEdit {
+    /// The char was removed
+    Removed {
+        /// Char index in the input
+        position: usize,
+        /// The removed char
+        character: char,
+    },
+    /// A lowercase letter was uppercased
+    Uppercased {
+        /// Char index in the input
+        position: usize,
+        /// The lowercase letter
+        from: char,
+        /// The uppercase letter
+        to: char,
+    },
+    /// A compatibility form or lookalike was folded to ASCII, see [`fold_to_ascii`]
+    Folded {
+        /// Char index in the input
+        position: usize,
+        /// The code point in the input
+        from: char,
+        /// The ASCII char it was folded to
+        to: char,
+    },
+}
+
+/// Cleaned value and the edits made to get it, as returned by [`clean_with_report`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CleanReport<'a> {
+    /// The cleaned value, borrowed from the input when nothing changed
+    pub cleaned: Cow<'a, str>,
+    /// Every edit made, in order of position
+    pub edits: Vec<CleanEdit>,
+}
+
+impl CleanReport<'_> {
+    /// Whether the input was already clean
+    pub fn is_unchanged(&self) -> bool {
+        self.edits.is_empty()
+    }
+}
+
+/// Clean the input and report every change made, for audit trails.
+///
+/// When nothing changes the cleaned value borrows the input, so the common path does not
+/// allocate.
+///
+/// ```
+/// use sedol::{CleanEdit, CleanOptions};
+///
+/// let report = sedol::clean_with_report("bd-9MZZ7", CleanOptions::new().uppercase(true));
+/// assert_eq!("BD9MZZ7", report.cleaned);
+/// assert_eq!(
+///     CleanEdit::Removed { position: 2, character: '-' },
+///     report.edits[2]
+/// );
+///
+/// let report = sedol::clean_with_report("BD9MZZ7", CleanOptions::new());
+/// assert!(report.is_unchanged());
+/// ```
+pub fn clean_with_report(sedol: &str, options: CleanOptions) -> CleanReport<'_> {
+    let mut edits = Vec::new();
+    for (position, from) in sedol.chars().enumerate() {
+        let mut character = from;
+        if options.unicode {
+            if let Some(to) = fold_to_ascii(character) {
+                edits.push(CleanEdit::Folded { position, from, to });
+                character = to;
+            }
+        }
+        if !character.is_ascii_alphanumeric() {
+            edits.push(CleanEdit::Removed {
+                position,
+                character,
+            });
+        } else if options.uppercase && character.is_ascii_lowercase() {
+            let to = character.to_ascii_uppercase();
+            edits.push(CleanEdit::Uppercased {
+                position,
+                from: character,
+                to,
+            });
+        }
+    }
+    if edits.is_empty() {
+        return CleanReport {
+            cleaned: Cow::Borrowed(sedol),
+            edits,
+        };
+    }
+
+    let mut cleaned = String::with_capacity(sedol.len());
+    for mut character in sedol.chars() {
+        if options.unicode {
+            character = fold_to_ascii(character).unwrap_or(character);
+        }
+        if character.is_ascii_alphanumeric() {
+            if options.uppercase {
+                character = character.to_ascii_uppercase();
+            }
+            cleaned.push(character);
+        }
+    }
+    CleanReport {
+        cleaned: Cow::Owned(cleaned),
+        edits,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_clean() {
+        for input in ["BD-9MZ-Z7", "BD-9MZ-Z7??!!  ", " bd9mzz7", "ＢＤ９", ""] {
+            let report = clean_with_report(input, CleanOptions::new());
+            assert_eq!(crate::clean(input), report.cleaned);
+        }
+    }
+
+    #[test]
+    fn borrowed_when_unchanged() {
+        let report =
+            clean_with_report("BD9MZZ7", CleanOptions::new().uppercase(true).unicode(true));
+        assert!(matches!(report.cleaned, Cow::Borrowed("BD9MZZ7")));
+        assert!(report.is_unchanged());
+    }
+
+    #[test]
+    fn all_edits() {
+        let options = CleanOptions::new().uppercase(true).unicode(true);
+        let report = clean_with_report("ｂD 9", options);
+        assert_eq!("BD9", report.cleaned);
+        assert_eq!(
+            vec![
+                CleanEdit::Folded {
+                    position: 0,
+                    from: 'ｂ',
+                    to: 'b'
+                },
+                CleanEdit::Uppercased {
+                    position: 0,
+                    from: 'b',
+                    to: 'B'
+                },
+                CleanEdit::Removed {
+                    position: 2,
+                    character: ' '
+                },
+            ],
+            report.edits
+        );
+    }
+}
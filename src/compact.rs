@@ -0,0 +1,90 @@
+use crate::{calc_check_digit, Sedol, SedolChar};
+
+/// Number of distinct `u32` encodings, `31^6`; every encoding is below this
+const ENCODINGS: u32 = 31 * 31 * 31 * 31 * 31 * 31;
+
+impl Sedol {
+    /// Encode the SEDOL as a `u32`.
+    ///
+    /// The check digit is derived from the base, so only the six base chars are encoded: each
+    /// char's index in [`SedolChar::ALL`] is a base 31 digit, most significant first. The
+    /// result is below `31^6`, which fits in 30 bits.
+    ///
+    /// The encoding preserves ordering: `a < b` if and only if `a.to_u32() < b.to_u32()`,
+    /// so the integers can be used as sorted database keys as well as hash keys.
+    ///
+    /// ```
+    /// use sedol::Sedol;
+    ///
+    /// let sedol: Sedol = "BD9MZZ7".parse().unwrap();
+    /// let n = sedol.to_u32();
+    /// assert_eq!(Some(sedol), Sedol::from_u32(n));
+    /// ```
+    pub fn to_u32(&self) -> u32 {
+        self.base().bytes().fold(0, |n, byte| {
+            let index = SedolChar::lookup(byte).map_or(0, SedolChar::index);
+            n * 31 + u32::from(index)
+        })
+    }
+
+    /// Decode a `u32` from [`Sedol::to_u32`], recomputing the check digit.
+    ///
+    /// Returns `None` if the integer is not the encoding of a valid SEDOL: either it is not
+    /// below `31^6`, or it decodes to a base starting with a digit that contains a letter.
+    pub fn from_u32(n: u32) -> Option<Sedol> {
+        if n >= ENCODINGS {
+            return None;
+        }
+        let mut base = [0u8; 6];
+        let mut rest = n;
+        for byte in base.iter_mut().rev() {
+            *byte = SedolChar::from_index((rest % 31) as u8)?.as_char() as u8;
+            rest /= 31;
+        }
+        let mut sedol = String::from_utf8(base.to_vec()).ok()?;
+        sedol.push(calc_check_digit(&sedol));
+        sedol.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for s in ["0000000", "0263494", "5954135", "B15KXQ8", "BD9MZZ7"] {
+            let sedol: Sedol = s.parse().unwrap();
+            assert_eq!(Some(sedol), Sedol::from_u32(sedol.to_u32()));
+        }
+        let last = Sedol::from_u32(ENCODINGS - 1).unwrap();
+        assert_eq!("ZZZZZZ", last.base());
+        assert_eq!(None, Sedol::from_u32(ENCODINGS));
+        assert_eq!(None, Sedol::from_u32(u32::MAX));
+    }
+
+    #[test]
+    fn invalid_old_format() {
+        // "00000B" would be a digit followed by a letter
+        assert_eq!(None, Sedol::from_u32(10));
+        assert_eq!(Some("0000099".parse().unwrap()), Sedol::from_u32(9));
+    }
+
+    #[test]
+    fn ordering() {
+        let mut sedols: Vec<Sedol> = ["BD9MZZ7", "0263494", "B15KXQ8", "5954135"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let mut encoded: Vec<u32> = sedols.iter().map(Sedol::to_u32).collect();
+        sedols.sort();
+        encoded.sort();
+        assert_eq!(
+            sedols,
+            encoded
+                .into_iter()
+                .filter_map(Sedol::from_u32)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! CUSIP and CINS
+//!
+//! A CUSIP is a six character issuer code, a two character issue code and a check digit
+//! calculated with the modulus 10 "double-add-double" algorithm. A CINS is a CUSIP whose first
+//! character is a letter identifying the country or region of a foreign issuer.
+//!
+//! <https://en.wikipedia.org/wiki/CUSIP>
+//!
+//! # Examples
+//! ```
+//! use sedol::cusip::{self, CusipCountry};
+//!
+//! let cusip = cusip::validate("037833100").unwrap();
+//! assert_eq!("037833", cusip.issuer());
+//! assert_eq!("US0378331005", cusip.to_isin(CusipCountry::US).unwrap().as_str());
+//! assert_eq!(Ok('0'), cusip::calc_check_digit("03783310"));
+//!
+//! let cins = cusip::validate("G0052B105").unwrap();
+//! assert_eq!(Some("United Kingdom"), cins.cins_region());
+//! ```
+
+use std::{fmt, str::FromStr};
+
+pub use crate::errors::{CusipError, CusipFromIsinError};
+use crate::{
+    identifier::{self, check_length, double_add_double, impl_identifier, to_bytes},
+    isin::{Isin, IsinError},
+};
+
+/// Check if the CUSIP is valid.
+///
+/// We do the checks in the following order:
+/// 1. only digits 0-9, uppercase letters A-Z, `*`, `@` and `#` are present
+/// 2. the length of the string is 9
+/// 3. compute and compare the check digit
+pub fn validate(cusip: &str) -> Result<Cusip, CusipError> {
+    cusip.parse()
+}
+
+/// Calculate the check digit for a CUSIP.
+///
+/// The input must be 8 characters long, or 9 characters long in which case the last
+/// character is taken to be an existing check digit and is ignored.
+///
+/// Each character has a value (0-9 for digits, 10-35 for letters A-Z, 36 for `*`, 37 for `@`,
+/// 38 for `#`), every second value is doubled and the digits of the results are summed.
+pub fn calc_check_digit(cusip: &str) -> Result<char, CusipError> {
+    check_characters(cusip)?;
+    check_length(cusip, &[8, 9])?;
+    Ok(double_add_double(&cusip.as_bytes()[..8]))
+}
+
+/// Country prefixes of ISINs that embed a CUSIP, see [`Cusip::to_isin`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CusipCountry {
+    /// United States
+    US,
+    /// Canada
+    CA,
+}
+
+impl CusipCountry {
+    /// The two letter country code
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CusipCountry::US => "US",
+            CusipCountry::CA => "CA",
+        }
+    }
+}
+
+impl fmt::Display for CusipCountry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A validated CUSIP, stored inline as its nine ASCII bytes.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cusip([u8; 9]);
+
+impl Cusip {
+    /// Return the six character issuer code
+    pub fn issuer(&self) -> &str {
+        &self.as_str()[..6]
+    }
+
+    /// Return the two character issue code
+    pub fn issue(&self) -> &str {
+        &self.as_str()[6..8]
+    }
+
+    /// Return the check digit, i.e. the last character of the CUSIP
+    pub fn check_digit(&self) -> char {
+        self.0[8] as char
+    }
+
+    /// Whether this is a CINS, i.e. the first character is a letter
+    pub fn is_cins(&self) -> bool {
+        self.0[0].is_ascii_uppercase()
+    }
+
+    /// The country or region of the foreign issuer, if this is a CINS
+    pub fn cins_region(&self) -> Option<&'static str> {
+        let region = match self.0[0] {
+            b'A' => "Austria",
+            b'B' => "Belgium",
+            b'C' => "Canada",
+            b'D' => "Germany",
+            b'E' => "Spain",
+            b'F' => "France",
+            b'G' => "United Kingdom",
+            b'H' => "Switzerland",
+            b'J' => "Japan",
+            b'K' => "Denmark",
+            b'L' => "Luxembourg",
+            b'M' => "Middle East",
+            b'N' => "Netherlands",
+            b'P' => "South America",
+            b'Q' => "Australia",
+            b'R' => "Norway",
+            b'S' => "South Africa",
+            b'T' => "Italy",
+            b'U' => "United States",
+            b'V' => "Africa - Other",
+            b'W' => "Sweden",
+            b'X' => "Europe - Other",
+            b'Y' => "Asia",
+            _ => return None,
+        };
+        Some(region)
+    }
+
+    /// Convert to the ISIN for the country: the country code, the CUSIP and the ISIN check
+    /// digit.
+    ///
+    /// Fails for CUSIPs containing `*`, `@` or `#`, which cannot appear in an ISIN.
+    pub fn to_isin(&self, country: CusipCountry) -> Result<Isin, IsinError> {
+        Isin::from_nsin(country.as_str(), self.as_str())
+    }
+
+    /// Extract the CUSIP from a US or CA ISIN.
+    ///
+    /// Both the ISIN check digit and the CUSIP check digit are verified. ISINs of other
+    /// countries are rejected with [`CusipFromIsinError::NotCusipBased`].
+    ///
+    /// ```
+    /// use sedol::cusip::Cusip;
+    ///
+    /// assert_eq!("037833100", Cusip::from_isin("US0378331005").unwrap().as_str());
+    /// assert!(Cusip::from_isin("GB0002634946").is_err());
+    /// ```
+    pub fn from_isin(isin: &str) -> Result<Cusip, CusipFromIsinError> {
+        let isin: Isin = isin.parse().map_err(CusipFromIsinError::InvalidIsin)?;
+        if !matches!(isin.country_code(), "US" | "CA") {
+            return Err(CusipFromIsinError::NotCusipBased {
+                input: isin.to_string(),
+            });
+        }
+        isin.nsin()
+            .parse()
+            .map_err(CusipFromIsinError::InvalidCusip)
+    }
+}
+
+impl FromStr for Cusip {
+    type Err = CusipError;
+
+    fn from_str(cusip: &str) -> Result<Self, Self::Err> {
+        check_characters(cusip)?;
+        let bytes: [u8; 9] = to_bytes(cusip)?;
+        let got_check_digit = bytes[8] as char;
+        let calc_check_digit = double_add_double(&bytes[..8]);
+        if got_check_digit != calc_check_digit {
+            return Err(CusipError::InvalidCheckDigit {
+                got_check_digit,
+                calc_check_digit,
+                input: cusip.to_string(),
+            });
+        }
+        Ok(Cusip(bytes))
+    }
+}
+
+impl_identifier!(Cusip, CusipError, "CUSIP");
+
+/// Check that every char is a digit 0-9, an uppercase letter A-Z, `*`, `@` or `#`
+fn check_characters(cusip: &str) -> Result<(), CusipError> {
+    identifier::check_characters(
+        cusip,
+        |c| matches!(c, '0'..='9' | 'A'..='Z' | '*' | '@' | '#'),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        for cusip in [
+            "037833100",
+            "17275R102",
+            "38259P508",
+            "594918104",
+            "G0052B105",
+        ] {
+            assert_eq!(cusip, validate(cusip).unwrap().as_str());
+        }
+        assert_eq!(Ok('2'), calc_check_digit("17275R10"));
+        assert_eq!(Ok('8'), calc_check_digit("38259P509"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Err(CusipError::InvalidCharacter {
+                character: 'r',
+                position: 5,
+                input: "17275r102".to_string()
+            }),
+            validate("17275r102")
+        );
+        assert_eq!(
+            Err(CusipError::InvalidLength {
+                length: 8,
+                input: "03783310".to_string()
+            }),
+            validate("03783310")
+        );
+        assert_eq!(
+            Err(CusipError::InvalidCheckDigit {
+                got_check_digit: '1',
+                calc_check_digit: '0',
+                input: "037833101".to_string()
+            }),
+            validate("037833101")
+        );
+    }
+
+    #[test]
+    fn cins() {
+        let cusip = validate("037833100").unwrap();
+        assert!(!cusip.is_cins());
+        assert_eq!(None, cusip.cins_region());
+        let cins = validate("G0052B105").unwrap();
+        assert!(cins.is_cins());
+        assert_eq!("G0052B", cins.issuer());
+        assert_eq!("10", cins.issue());
+    }
+
+    #[test]
+    fn isin() {
+        let cusip = validate("17275R102").unwrap();
+        let isin = cusip.to_isin(CusipCountry::US).unwrap();
+        assert_eq!("US17275R1023", isin.as_str());
+        assert_eq!(Ok(cusip), Cusip::from_isin(isin.as_str()));
+        let isin = cusip.to_isin(CusipCountry::CA).unwrap();
+        assert_eq!(Ok(cusip), Cusip::from_isin(isin.as_str()));
+
+        let private = format!("0378*@#1{}", calc_check_digit("0378*@#1").unwrap());
+        assert!(validate(&private)
+            .unwrap()
+            .to_isin(CusipCountry::US)
+            .is_err());
+        assert!(matches!(
+            Cusip::from_isin("GB0002634946"),
+            Err(CusipFromIsinError::NotCusipBased { .. })
+        ));
+    }
+}
@@ -2,36 +2,181 @@
 use std::{error::Error, fmt};
 
 /// Enum representing reasons why a SEDOL string might be invalid
-#[derive(Debug, PartialEq)]
+///
+/// Every variant keeps a copy of the input that failed, available from [`SedolError::input`].
+/// Positions are zero-based char indices into that input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum SedolError {
     /// Invalid character present, only digits 0-9 and letters B-Z (excluding vowels) are allowed
     InvalidCharacter {
         /// The invalid char
         character: char,
+        /// Zero-based char index of the invalid char in the input
+        position: usize,
+        /// The input that failed
+        input: String,
+    },
+    /// Length must be 7 (or 6 when calculating a check digit)
+    InvalidLength {
+        /// The length of the input in chars
+        length: usize,
+        /// The input that failed
+        input: String,
     },
-    /// Length must be 7
-    InvalidLength,
     /// First char is a digit but rest of string is not ASCII digits. Old format SEDOLs contain only digits.
-    InvalidOldFormat,
+    InvalidOldFormat {
+        /// A char that is not a digit (the first one, for `validate`)
+        character: char,
+        /// Zero-based char index of that char in the input
+        position: usize,
+        /// The input that failed
+        input: String,
+    },
     /// Check digit is invalid
     InvalidCheckDigit {
         /// The check digit provided in the input
         got_check_digit: char,
         /// The calculated check digit
         calc_check_digit: char,
+        /// Zero-based char index of the check digit in the input
+        position: usize,
+        /// The input that failed
+        input: String,
+    },
+    /// SEDOL is in the user-defined range (first char is 9), which the validator was set to reject
+    UserDefined {
+        /// Zero-based char index of the leading 9 in the input
+        position: usize,
+        /// The input that failed
+        input: String,
+    },
+    /// SEDOL is in the old all-numeric format, which the validator was set to reject
+    OldFormatNotAllowed {
+        /// The input that failed
+        input: String,
     },
 }
 
+impl SedolError {
+    /// The kind of error, without any of its details
+    pub fn kind(&self) -> SedolErrorKind {
+        match self {
+            SedolError::InvalidCharacter { .. } => SedolErrorKind::InvalidCharacter,
+            SedolError::InvalidLength { .. } => SedolErrorKind::InvalidLength,
+            SedolError::InvalidOldFormat { .. } => SedolErrorKind::InvalidOldFormat,
+            SedolError::InvalidCheckDigit { .. } => SedolErrorKind::InvalidCheckDigit,
+            SedolError::UserDefined { .. } => SedolErrorKind::UserDefined,
+            SedolError::OldFormatNotAllowed { .. } => SedolErrorKind::OldFormatNotAllowed,
+        }
+    }
+
+    /// Stable machine-readable error code, see [`SedolErrorKind::code`]
+    pub fn code(&self) -> &'static str {
+        self.kind().code()
+    }
+
+    /// The input that failed validation
+    pub fn input(&self) -> &str {
+        match self {
+            SedolError::InvalidCharacter { input, .. }
+            | SedolError::InvalidLength { input, .. }
+            | SedolError::InvalidOldFormat { input, .. }
+            | SedolError::InvalidCheckDigit { input, .. }
+            | SedolError::UserDefined { input, .. }
+            | SedolError::OldFormatNotAllowed { input } => input,
+        }
+    }
+
+    /// Zero-based char index of the offending char, if the error concerns a single char
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            SedolError::InvalidCharacter { position, .. }
+            | SedolError::InvalidOldFormat { position, .. }
+            | SedolError::InvalidCheckDigit { position, .. }
+            | SedolError::UserDefined { position, .. } => Some(*position),
+            SedolError::InvalidLength { .. } | SedolError::OldFormatNotAllowed { .. } => None,
+        }
+    }
+}
+
+/// Fieldless counterpart of [`SedolError`], for grouping, counting and routing errors.
+///
+/// Each kind has a code from [`SedolErrorKind::code`]. The codes are a stable contract: a
+/// code is never reused or reassigned, and new kinds only ever get new codes.
+///
+/// | Kind                  | Code       |
+/// |-----------------------|------------|
+/// | `InvalidCharacter`    | `SEDOL001` |
+/// | `InvalidLength`       | `SEDOL002` |
+/// | `InvalidOldFormat`    | `SEDOL003` |
+/// | `InvalidCheckDigit`   | `SEDOL004` |
+/// | `UserDefined`         | `SEDOL005` |
+/// | `OldFormatNotAllowed` | `SEDOL006` |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum SedolErrorKind {
+    /// See [`SedolError::InvalidCharacter`]
+    InvalidCharacter,
+    /// See [`SedolError::InvalidLength`]
+    InvalidLength,
+    /// See [`SedolError::InvalidOldFormat`]
+    InvalidOldFormat,
+    /// See [`SedolError::InvalidCheckDigit`]
+    InvalidCheckDigit,
+    /// See [`SedolError::UserDefined`]
+    UserDefined,
+    /// See [`SedolError::OldFormatNotAllowed`]
+    OldFormatNotAllowed,
+}
+
+impl SedolErrorKind {
+    /// All kinds, in code order
+    pub const ALL: [SedolErrorKind; 6] = [
+        SedolErrorKind::InvalidCharacter,
+        SedolErrorKind::InvalidLength,
+        SedolErrorKind::InvalidOldFormat,
+        SedolErrorKind::InvalidCheckDigit,
+        SedolErrorKind::UserDefined,
+        SedolErrorKind::OldFormatNotAllowed,
+    ];
+
+    /// Stable machine-readable error code
+    pub fn code(&self) -> &'static str {
+        match self {
+            SedolErrorKind::InvalidCharacter => "SEDOL001",
+            SedolErrorKind::InvalidLength => "SEDOL002",
+            SedolErrorKind::InvalidOldFormat => "SEDOL003",
+            SedolErrorKind::InvalidCheckDigit => "SEDOL004",
+            SedolErrorKind::UserDefined => "SEDOL005",
+            SedolErrorKind::OldFormatNotAllowed => "SEDOL006",
+        }
+    }
+
+    /// Look up the kind for an error code
+    pub fn from_code(code: &str) -> Option<SedolErrorKind> {
+        SedolErrorKind::ALL
+            .into_iter()
+            .find(|kind| kind.code() == code)
+    }
+}
+
+impl fmt::Display for SedolErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
 impl fmt::Display for SedolError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            SedolError::InvalidCharacter { character } => {
+            SedolError::InvalidCharacter { character, .. } => {
                 write!(f, "invalid character {}", character)
             }
-            SedolError::InvalidLength => {
+            SedolError::InvalidLength { .. } => {
                 write!(f, "invalid length, expected 7")
             }
-            SedolError::InvalidOldFormat => {
+            SedolError::InvalidOldFormat { .. } => {
                 write!(
                     f,
                     "invalid format, expected all digits when first char is digit"
@@ -40,6 +185,7 @@ impl fmt::Display for SedolError {
             SedolError::InvalidCheckDigit {
                 got_check_digit,
                 calc_check_digit,
+                ..
             } => {
                 write!(
                     f,
@@ -47,8 +193,403 @@ impl fmt::Display for SedolError {
                     got_check_digit, calc_check_digit
                 )
             }
+            SedolError::UserDefined { .. } => {
+                write!(f, "user-defined SEDOL not allowed")
+            }
+            SedolError::OldFormatNotAllowed { .. } => {
+                write!(f, "old format SEDOL not allowed")
+            }
         }
     }
 }
 
 impl Error for SedolError {}
+
+/// Enum representing reasons why an ISIN string might be invalid
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IsinError {
+    /// Invalid character present, only digits 0-9 and uppercase letters A-Z are allowed
+    InvalidCharacter {
+        /// The invalid char
+        character: char,
+        /// Zero-based char index of the invalid char in the input
+        position: usize,
+        /// The input that failed
+        input: String,
+    },
+    /// Length must be 12 (or 11 when calculating a check digit)
+    InvalidLength {
+        /// The length of the input in chars
+        length: usize,
+        /// The input that failed
+        input: String,
+    },
+    /// The first two chars are not letters, so cannot be a country code
+    InvalidCountryCode {
+        /// The first two chars of the input
+        country_code: String,
+        /// The input that failed
+        input: String,
+    },
+    /// Check digit is invalid
+    InvalidCheckDigit {
+        /// The check digit provided in the input
+        got_check_digit: char,
+        /// The calculated check digit
+        calc_check_digit: char,
+        /// The input that failed
+        input: String,
+    },
+}
+
+impl IsinError {
+    /// The input that failed validation
+    pub fn input(&self) -> &str {
+        match self {
+            IsinError::InvalidCharacter { input, .. }
+            | IsinError::InvalidLength { input, .. }
+            | IsinError::InvalidCountryCode { input, .. }
+            | IsinError::InvalidCheckDigit { input, .. } => input,
+        }
+    }
+}
+
+impl fmt::Display for IsinError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IsinError::InvalidCharacter { character, .. } => {
+                write!(f, "invalid character {}", character)
+            }
+            IsinError::InvalidLength { .. } => {
+                write!(f, "invalid length, expected 12")
+            }
+            IsinError::InvalidCountryCode { country_code, .. } => {
+                write!(f, "invalid country code {}", country_code)
+            }
+            IsinError::InvalidCheckDigit {
+                got_check_digit,
+                calc_check_digit,
+                ..
+            } => {
+                write!(
+                    f,
+                    "invalid check digit {}, expected {}",
+                    got_check_digit, calc_check_digit
+                )
+            }
+        }
+    }
+}
+
+impl Error for IsinError {}
+
+/// Enum representing reasons why a CUSIP string might be invalid
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CusipError {
+    /// Invalid character present, only digits 0-9, uppercase letters A-Z, `*`, `@` and `#` are allowed
+    InvalidCharacter {
+        /// The invalid char
+        character: char,
+        /// Zero-based char index of the invalid char in the input
+        position: usize,
+        /// The input that failed
+        input: String,
+    },
+    /// Length must be 9 (or 8 when calculating a check digit)
+    InvalidLength {
+        /// The length of the input in chars
+        length: usize,
+        /// The input that failed
+        input: String,
+    },
+    /// Check digit is invalid
+    InvalidCheckDigit {
+        /// The check digit provided in the input
+        got_check_digit: char,
+        /// The calculated check digit
+        calc_check_digit: char,
+        /// The input that failed
+        input: String,
+    },
+}
+
+impl CusipError {
+    /// The input that failed validation
+    pub fn input(&self) -> &str {
+        match self {
+            CusipError::InvalidCharacter { input, .. }
+            | CusipError::InvalidLength { input, .. }
+            | CusipError::InvalidCheckDigit { input, .. } => input,
+        }
+    }
+}
+
+impl fmt::Display for CusipError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CusipError::InvalidCharacter { character, .. } => {
+                write!(f, "invalid character {}", character)
+            }
+            CusipError::InvalidLength { .. } => {
+                write!(f, "invalid length, expected 9")
+            }
+            CusipError::InvalidCheckDigit {
+                got_check_digit,
+                calc_check_digit,
+                ..
+            } => {
+                write!(
+                    f,
+                    "invalid check digit {}, expected {}",
+                    got_check_digit, calc_check_digit
+                )
+            }
+        }
+    }
+}
+
+impl Error for CusipError {}
+
+/// Enum representing reasons why a FIGI string might be invalid
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FigiError {
+    /// Invalid character present, only digits 0-9 and uppercase consonants are allowed
+    InvalidCharacter {
+        /// The invalid char
+        character: char,
+        /// Zero-based char index of the invalid char in the input
+        position: usize,
+        /// The input that failed
+        input: String,
+    },
+    /// Length must be 12 (or 11 when calculating a check digit)
+    InvalidLength {
+        /// The length of the input in chars
+        length: usize,
+        /// The input that failed
+        input: String,
+    },
+    /// The first two chars are not consonants, or are one of the forbidden prefixes
+    InvalidPrefix {
+        /// The first two chars of the input
+        prefix: String,
+        /// The input that failed
+        input: String,
+    },
+    /// The third char must be `G`
+    InvalidThirdCharacter {
+        /// The third char of the input
+        character: char,
+        /// The input that failed
+        input: String,
+    },
+    /// Check digit is invalid
+    InvalidCheckDigit {
+        /// The check digit provided in the input
+        got_check_digit: char,
+        /// The calculated check digit
+        calc_check_digit: char,
+        /// The input that failed
+        input: String,
+    },
+}
+
+impl FigiError {
+    /// The input that failed validation
+    pub fn input(&self) -> &str {
+        match self {
+            FigiError::InvalidCharacter { input, .. }
+            | FigiError::InvalidLength { input, .. }
+            | FigiError::InvalidPrefix { input, .. }
+            | FigiError::InvalidThirdCharacter { input, .. }
+            | FigiError::InvalidCheckDigit { input, .. } => input,
+        }
+    }
+}
+
+impl fmt::Display for FigiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FigiError::InvalidCharacter { character, .. } => {
+                write!(f, "invalid character {}", character)
+            }
+            FigiError::InvalidLength { .. } => {
+                write!(f, "invalid length, expected 12")
+            }
+            FigiError::InvalidPrefix { prefix, .. } => {
+                write!(f, "invalid prefix {}", prefix)
+            }
+            FigiError::InvalidThirdCharacter { character, .. } => {
+                write!(f, "invalid third character {}, expected G", character)
+            }
+            FigiError::InvalidCheckDigit {
+                got_check_digit,
+                calc_check_digit,
+                ..
+            } => {
+                write!(
+                    f,
+                    "invalid check digit {}, expected {}",
+                    got_check_digit, calc_check_digit
+                )
+            }
+        }
+    }
+}
+
+impl Error for FigiError {}
+
+/// Enum representing reasons why an LEI string might be invalid
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LeiError {
+    /// Invalid character present, only digits 0-9 and uppercase letters A-Z are allowed
+    InvalidCharacter {
+        /// The invalid char
+        character: char,
+        /// Zero-based char index of the invalid char in the input
+        position: usize,
+        /// The input that failed
+        input: String,
+    },
+    /// Length must be 20 (or 18 when calculating check digits)
+    InvalidLength {
+        /// The length of the input in chars
+        length: usize,
+        /// The input that failed
+        input: String,
+    },
+    /// Check digits are invalid
+    InvalidCheckDigits {
+        /// The check digits provided in the input
+        got_check_digits: String,
+        /// The calculated check digits
+        calc_check_digits: String,
+        /// The input that failed
+        input: String,
+    },
+}
+
+impl LeiError {
+    /// The input that failed validation
+    pub fn input(&self) -> &str {
+        match self {
+            LeiError::InvalidCharacter { input, .. }
+            | LeiError::InvalidLength { input, .. }
+            | LeiError::InvalidCheckDigits { input, .. } => input,
+        }
+    }
+}
+
+impl fmt::Display for LeiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LeiError::InvalidCharacter { character, .. } => {
+                write!(f, "invalid character {}", character)
+            }
+            LeiError::InvalidLength { .. } => {
+                write!(f, "invalid length, expected 20")
+            }
+            LeiError::InvalidCheckDigits {
+                got_check_digits,
+                calc_check_digits,
+                ..
+            } => {
+                write!(
+                    f,
+                    "invalid check digits {}, expected {}",
+                    got_check_digits, calc_check_digits
+                )
+            }
+        }
+    }
+}
+
+impl Error for LeiError {}
+
+/// Enum representing reasons why an ISIN might not convert to a SEDOL, see
+/// [`Sedol::from_isin`](crate::Sedol::from_isin)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FromIsinError {
+    /// The ISIN is invalid
+    InvalidIsin(IsinError),
+    /// The ISIN is valid, but not one of the GB, IE, GG, JE or IM ISINs that embed a SEDOL
+    NotSedolBased {
+        /// The input that failed
+        input: String,
+    },
+    /// The SEDOL embedded in the ISIN is invalid
+    InvalidSedol(SedolError),
+}
+
+impl fmt::Display for FromIsinError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FromIsinError::InvalidIsin(e) => {
+                write!(f, "invalid ISIN: {}", e)
+            }
+            FromIsinError::NotSedolBased { input } => {
+                write!(f, "ISIN {} does not contain a SEDOL", input)
+            }
+            FromIsinError::InvalidSedol(e) => {
+                write!(f, "invalid SEDOL in ISIN: {}", e)
+            }
+        }
+    }
+}
+
+impl Error for FromIsinError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FromIsinError::InvalidIsin(e) => Some(e),
+            FromIsinError::InvalidSedol(e) => Some(e),
+            FromIsinError::NotSedolBased { .. } => None,
+        }
+    }
+}
+
+/// Enum representing reasons why an ISIN might not convert to a CUSIP, see
+/// [`Cusip::from_isin`](crate::cusip::Cusip::from_isin)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CusipFromIsinError {
+    /// The ISIN is invalid
+    InvalidIsin(IsinError),
+    /// The ISIN is valid, but not one of the US or CA ISINs that embed a CUSIP
+    NotCusipBased {
+        /// The input that failed
+        input: String,
+    },
+    /// The CUSIP embedded in the ISIN is invalid
+    InvalidCusip(CusipError),
+}
+
+impl fmt::Display for CusipFromIsinError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CusipFromIsinError::InvalidIsin(e) => {
+                write!(f, "invalid ISIN: {}", e)
+            }
+            CusipFromIsinError::NotCusipBased { input } => {
+                write!(f, "ISIN {} does not contain a CUSIP", input)
+            }
+            CusipFromIsinError::InvalidCusip(e) => {
+                write!(f, "invalid CUSIP in ISIN: {}", e)
+            }
+        }
+    }
+}
+
+impl Error for CusipFromIsinError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CusipFromIsinError::InvalidIsin(e) => Some(e),
+            CusipFromIsinError::InvalidCusip(e) => Some(e),
+            CusipFromIsinError::NotCusipBased { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! FIGI (Bloomberg Open Symbology)
+//!
+//! A Financial Instrument Global Identifier is twelve characters: a two letter prefix, the
+//! letter `G`, eight further characters and a check digit. Only digits and uppercase
+//! consonants are used, and some prefixes are forbidden to avoid confusion with ISINs.
+//!
+//! <https://www.openfigi.com/about/figi>
+//!
+//! # Examples
+//! ```
+//! use sedol::figi;
+//!
+//! let figi = figi::validate("BBG000BLNNH6").unwrap();
+//! assert_eq!("BB", figi.prefix());
+//! assert_eq!(Ok('6'), figi::calc_check_digit("BBG000BLNNH"));
+//! assert!(figi::validate("GBG000BLNNH6").is_err());
+//! ```
+
+use std::{fmt, str::FromStr};
+
+pub use crate::errors::FigiError;
+use crate::identifier::{self, check_length, double_add_double, impl_identifier, to_bytes};
+
+/// Prefixes that are never used, as they are ISIN country codes
+pub const FORBIDDEN_PREFIXES: [&str; 7] = ["BS", "BM", "GG", "GB", "GH", "KY", "VG"];
+
+/// Check if the FIGI is valid.
+///
+/// We do the checks in the following order:
+/// 1. only digits 0-9 and uppercase consonants are present
+/// 2. the length of the string is 12
+/// 3. the first two chars are consonants and not a forbidden prefix
+/// 4. the third char is `G`
+/// 5. compute and compare the check digit
+pub fn validate(figi: &str) -> Result<Figi, FigiError> {
+    figi.parse()
+}
+
+/// Calculate the check digit for a FIGI.
+///
+/// The input must be 11 characters long, or 12 characters long in which case the last
+/// character is taken to be an existing check digit and is ignored. All characters must be
+/// digits 0-9 or uppercase consonants.
+///
+/// Each character has a value (0-9 for digits, 10-35 for letters A-Z), every second value is
+/// doubled and the digits of the results are summed.
+pub fn calc_check_digit(figi: &str) -> Result<char, FigiError> {
+    check_characters(figi)?;
+    check_length(figi, &[11, 12])?;
+    Ok(double_add_double(&figi.as_bytes()[..11]))
+}
+
+/// A validated FIGI, stored inline as its twelve ASCII bytes.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Figi([u8; 12]);
+
+impl Figi {
+    /// Return the two letter prefix
+    pub fn prefix(&self) -> &str {
+        &self.as_str()[..2]
+    }
+
+    /// Return the eight characters after the `G`
+    pub fn id(&self) -> &str {
+        &self.as_str()[3..11]
+    }
+
+    /// Return the check digit, i.e. the last character of the FIGI
+    pub fn check_digit(&self) -> char {
+        self.0[11] as char
+    }
+}
+
+impl FromStr for Figi {
+    type Err = FigiError;
+
+    fn from_str(figi: &str) -> Result<Self, Self::Err> {
+        check_characters(figi)?;
+        let bytes: [u8; 12] = to_bytes(figi)?;
+        let prefix = &figi[..2];
+        if !bytes[..2].iter().all(u8::is_ascii_uppercase) || FORBIDDEN_PREFIXES.contains(&prefix) {
+            return Err(FigiError::InvalidPrefix {
+                prefix: prefix.to_string(),
+                input: figi.to_string(),
+            });
+        }
+        if bytes[2] != b'G' {
+            return Err(FigiError::InvalidThirdCharacter {
+                character: bytes[2] as char,
+                input: figi.to_string(),
+            });
+        }
+        let got_check_digit = bytes[11] as char;
+        let calc_check_digit = double_add_double(&bytes[..11]);
+        if got_check_digit != calc_check_digit {
+            return Err(FigiError::InvalidCheckDigit {
+                got_check_digit,
+                calc_check_digit,
+                input: figi.to_string(),
+            });
+        }
+        Ok(Figi(bytes))
+    }
+}
+
+impl_identifier!(Figi, FigiError, "FIGI");
+
+/// Check that every char is a digit 0-9 or an uppercase consonant
+fn check_characters(figi: &str) -> Result<(), FigiError> {
+    identifier::check_characters(figi, |c| {
+        c.is_ascii_digit() || (c.is_ascii_uppercase() && !matches!(c, 'A' | 'E' | 'I' | 'O' | 'U'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        for figi in ["BBG000BLNNH6", "BBG000B9XRY4", "BBG000BPH459"] {
+            assert_eq!(figi, validate(figi).unwrap().as_str());
+        }
+        assert_eq!(Ok('4'), calc_check_digit("BBG000B9XRY"));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Err(FigiError::InvalidCharacter {
+                character: 'A',
+                position: 7,
+                input: "BBG000BANNH6".to_string()
+            }),
+            validate("BBG000BANNH6")
+        );
+        assert_eq!(
+            Err(FigiError::InvalidLength {
+                length: 11,
+                input: "BBG000BLNNH".to_string()
+            }),
+            validate("BBG000BLNNH")
+        );
+        assert_eq!(
+            Err(FigiError::InvalidPrefix {
+                prefix: "KY".to_string(),
+                input: "KYG000BLNNH6".to_string()
+            }),
+            validate("KYG000BLNNH6")
+        );
+        assert!(matches!(
+            validate("1BG000BLNNH6"),
+            Err(FigiError::InvalidPrefix { .. })
+        ));
+        assert_eq!(
+            Err(FigiError::InvalidThirdCharacter {
+                character: 'B',
+                input: "BBB000BLNNH6".to_string()
+            }),
+            validate("BBB000BLNNH6")
+        );
+        assert_eq!(
+            Err(FigiError::InvalidCheckDigit {
+                got_check_digit: '7',
+                calc_check_digit: '6',
+                input: "BBG000BLNNH7".to_string()
+            }),
+            validate("BBG000BLNNH7")
+        );
+    }
+
+    #[test]
+    fn parts() {
+        let figi = validate("BBG000B9XRY4").unwrap();
+        assert_eq!("BB", figi.prefix());
+        assert_eq!("000B9XRY", figi.id());
+        assert_eq!('4', figi.check_digit());
+    }
+}
@@ -0,0 +1,132 @@
+use std::{fmt, ops::Deref};
+
+use crate::{Sedol, SedolStr};
+
+/// The format of a valid SEDOL, see [`SedolStr::format`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SedolFormat {
+    /// Old all-numeric format, e.g. `0263494`
+    Legacy,
+    /// Alphanumeric format starting with a letter, issued since 2004, e.g. `B15KXQ8`
+    Modern,
+    /// User-defined range, all-numeric starting with 9
+    UserDefined,
+}
+
+impl SedolStr {
+    /// Classify the SEDOL by format
+    ///
+    /// ```
+    /// use sedol::{Sedol, SedolFormat};
+    ///
+    /// let sedol: Sedol = "B15KXQ8".parse().unwrap();
+    /// assert_eq!(SedolFormat::Modern, sedol.format());
+    /// assert_eq!(SedolFormat::Legacy, sedol::validate("0263494").unwrap().format());
+    /// ```
+    pub fn format(&self) -> SedolFormat {
+        match self.as_bytes()[0] {
+            b'9' => SedolFormat::UserDefined,
+            b'0'..=b'8' => SedolFormat::Legacy,
+            _ => SedolFormat::Modern,
+        }
+    }
+}
+
+impl Sedol {
+    /// Convert into a [`LegacySedol`] if the SEDOL is all-numeric, including the user-defined range
+    pub fn into_legacy(self) -> Option<LegacySedol> {
+        match self.format() {
+            SedolFormat::Legacy | SedolFormat::UserDefined => Some(LegacySedol(self)),
+            SedolFormat::Modern => None,
+        }
+    }
+
+    /// Convert into a [`ModernSedol`] if the SEDOL starts with a letter
+    pub fn into_modern(self) -> Option<ModernSedol> {
+        match self.format() {
+            SedolFormat::Modern => Some(ModernSedol(self)),
+            SedolFormat::Legacy | SedolFormat::UserDefined => None,
+        }
+    }
+}
+
+/// A [`Sedol`] known to be in the old all-numeric format, see [`Sedol::into_legacy`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LegacySedol(Sedol);
+
+/// A [`Sedol`] known to be in the alphanumeric format, see [`Sedol::into_modern`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModernSedol(Sedol);
+
+impl LegacySedol {
+    /// The SEDOL as a number, e.g. `263494` for `0263494`
+    pub fn number(&self) -> u32 {
+        self.0.bytes().fold(0, |n, b| n * 10 + u32::from(b - b'0'))
+    }
+}
+
+impl ModernSedol {
+    /// The leading letter
+    pub fn letter(&self) -> char {
+        self.0.as_bytes()[0] as char
+    }
+}
+
+macro_rules! impl_typed_sedol {
+    ($name:ident) => {
+        impl Deref for $name {
+            type Target = Sedol;
+
+            fn deref(&self) -> &Sedol {
+                &self.0
+            }
+        }
+
+        impl From<$name> for Sedol {
+            fn from(sedol: $name) -> Sedol {
+                sedol.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+impl_typed_sedol!(LegacySedol);
+impl_typed_sedol!(ModernSedol);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sedol(s: &str) -> Sedol {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(SedolFormat::Legacy, sedol("5954135").format());
+        assert_eq!(SedolFormat::Legacy, sedol("0263494").format());
+        assert_eq!(SedolFormat::Modern, sedol("BD9MZZ7").format());
+        assert_eq!(SedolFormat::UserDefined, sedol("9123458").format());
+    }
+
+    #[test]
+    fn typed() {
+        let legacy = sedol("0263494").into_legacy().unwrap();
+        assert_eq!(263494, legacy.number());
+        assert_eq!("0263494", legacy.to_string());
+        assert_eq!(None, sedol("0263494").into_modern());
+        assert!(sedol("9123458").into_legacy().is_some());
+
+        let modern = sedol("BD9MZZ7").into_modern().unwrap();
+        assert_eq!('B', modern.letter());
+        assert_eq!("BD9MZZ", modern.base());
+        assert_eq!(sedol("BD9MZZ7"), Sedol::from(modern));
+        assert_eq!(None, sedol("BD9MZZ7").into_legacy());
+    }
+}
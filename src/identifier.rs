@@ -0,0 +1,168 @@
+use crate::errors::{CusipError, FigiError, IsinError, LeiError};
+
+/// The errors shared by the fixed-length ASCII identifiers: ISIN, CUSIP, LEI and FIGI
+pub(crate) trait IdentifierError {
+    fn invalid_character(character: char, position: usize, input: &str) -> Self;
+    fn invalid_length(length: usize, input: &str) -> Self;
+}
+
+/// Check that every char is allowed
+pub(crate) fn check_characters<E: IdentifierError>(
+    input: &str,
+    is_allowed: impl Fn(char) -> bool,
+) -> Result<(), E> {
+    match input.chars().enumerate().find(|&(_, c)| !is_allowed(c)) {
+        Some((position, character)) => Err(E::invalid_character(character, position, input)),
+        None => Ok(()),
+    }
+}
+
+/// Check that the length is one of `lengths`
+pub(crate) fn check_length<E: IdentifierError>(input: &str, lengths: &[usize]) -> Result<(), E> {
+    if lengths.contains(&input.len()) {
+        Ok(())
+    } else {
+        Err(E::invalid_length(input.len(), input))
+    }
+}
+
+/// Copy the input into an array, checking that it is exactly `N` bytes long
+pub(crate) fn to_bytes<E: IdentifierError, const N: usize>(input: &str) -> Result<[u8; N], E> {
+    input
+        .as_bytes()
+        .try_into()
+        .map_err(|_| E::invalid_length(input.len(), input))
+}
+
+/// Calculate the modulus 10 double-add-double check digit, as used by CUSIP and FIGI.
+///
+/// Each char has a value (0-9 for digits, 10-35 for letters A-Z, 36 for `*`, 37 for `@`, 38
+/// for `#`), every second value is doubled and the digits of the results are summed. The
+/// input must be valid chars.
+pub(crate) fn double_add_double(input: &[u8]) -> char {
+    let sum: u32 = input
+        .iter()
+        .enumerate()
+        .map(|(i, &byte)| {
+            let value = match byte {
+                b'0'..=b'9' => u32::from(byte - b'0'),
+                b'*' => 36,
+                b'@' => 37,
+                b'#' => 38,
+                _ => u32::from(byte.saturating_sub(b'A')) + 10,
+            };
+            let value = if i % 2 == 1 { value * 2 } else { value };
+            value / 10 + value % 10
+        })
+        .sum();
+    (b'0' + ((10 - sum % 10) % 10) as u8) as char
+}
+
+/// Implement `as_str` and the string conversions for an identifier wrapping its ASCII bytes
+macro_rules! impl_identifier {
+    ($name:ident, $error:ident, $label:literal) => {
+        impl $name {
+            #[doc = concat!("Return the ", $label, " as a string slice")]
+            pub fn as_str(&self) -> &str {
+                // Only ever built from a validated string, which is pure ASCII, so the
+                // fallback is never used.
+                std::str::from_utf8(&self.0).unwrap_or_default()
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = $error;
+
+            fn try_from(input: &str) -> Result<Self, Self::Error> {
+                input.parse()
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = $error;
+
+            fn try_from(input: String) -> Result<Self, Self::Error> {
+                input.parse()
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.as_str())
+                    .finish()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_identifier;
+
+macro_rules! impl_identifier_error {
+    ($error:ident) => {
+        impl IdentifierError for $error {
+            fn invalid_character(character: char, position: usize, input: &str) -> Self {
+                $error::InvalidCharacter {
+                    character,
+                    position,
+                    input: input.to_string(),
+                }
+            }
+
+            fn invalid_length(length: usize, input: &str) -> Self {
+                $error::InvalidLength {
+                    length,
+                    input: input.to_string(),
+                }
+            }
+        }
+    };
+}
+
+impl_identifier_error!(IsinError);
+impl_identifier_error!(CusipError);
+impl_identifier_error!(LeiError);
+impl_identifier_error!(FigiError);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_digit() {
+        assert_eq!('6', double_add_double(b"BBG000BLNNH"));
+        assert_eq!('4', double_add_double(b"BBG000B9XRY"));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Err(FigiError::InvalidCharacter {
+                character: 'a',
+                position: 1,
+                input: "Ha".to_string()
+            }),
+            check_characters("Ha", |c| c.is_ascii_uppercase())
+        );
+        assert_eq!(
+            Err(FigiError::InvalidLength {
+                length: 3,
+                input: "BBG".to_string()
+            }),
+            to_bytes::<FigiError, 12>("BBG")
+        );
+        assert_eq!(Ok(()), check_length::<FigiError>("BBG000BLNNH", &[11, 12]));
+    }
+}
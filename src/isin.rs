@@ -0,0 +1,366 @@
+//! ISIN (ISO 6166)
+//!
+//! An ISIN is a two letter country code, a nine character national security identifier
+//! (NSIN) and a check digit calculated with the Luhn algorithm.
+//!
+//! <https://en.wikipedia.org/wiki/International_Securities_Identification_Number>
+//!
+//! # Examples
+//! ```
+//! use sedol::isin;
+//!
+//! let isin = isin::validate("US0378331005").unwrap();
+//! assert_eq!("US", isin.country_code());
+//! assert_eq!("037833100", isin.nsin());
+//! assert_eq!(Ok('5'), isin::calc_check_digit("US037833100"));
+//! assert!(isin::validate("US0378331006").is_err());
+//! ```
+
+use std::{fmt, str::FromStr};
+
+pub use crate::errors::IsinError;
+use crate::{
+    identifier::{self, check_length, impl_identifier, to_bytes},
+    FromIsinError, Sedol,
+};
+
+/// Check if the ISIN is valid.
+///
+/// We do the checks in the following order:
+/// 1. only digits 0-9 and uppercase letters A-Z are present
+/// 2. the length of the string is 12
+/// 3. the first two chars, the country code, are letters
+/// 4. compute and compare the check digit
+pub fn validate(isin: &str) -> Result<Isin, IsinError> {
+    isin.parse()
+}
+
+/// Calculate the ISO 6166 check digit for an ISIN.
+///
+/// The input must be 11 characters long, or 12 characters long in which case the last
+/// character is taken to be an existing check digit and is ignored. All characters must be
+/// digits 0-9 or uppercase letters A-Z.
+///
+/// Letters are expanded to two digits (A=10 to Z=35), then the Luhn algorithm is applied to
+/// the digits.
+pub fn calc_check_digit(isin: &str) -> Result<char, IsinError> {
+    check_characters(isin)?;
+    check_length(isin, &[11, 12])?;
+    Ok(luhn_check_digit(&isin.as_bytes()[..11]))
+}
+
+/// A validated ISIN, stored inline as its twelve ASCII bytes.
+///
+/// ```
+/// use sedol::isin::Isin;
+///
+/// let isin: Isin = "GB0002634946".parse().unwrap();
+/// assert_eq!("GB", isin.country_code());
+/// assert_eq!('6', isin.check_digit());
+/// assert_eq!("0263494", isin.to_sedol().unwrap().as_str());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Isin([u8; 12]);
+
+impl Isin {
+    /// Build an ISIN from a country code and a nine character NSIN, calculating the check digit
+    ///
+    /// ```
+    /// use sedol::isin::Isin;
+    ///
+    /// assert_eq!("US0378331005", Isin::from_nsin("US", "037833100").unwrap().as_str());
+    /// ```
+    pub fn from_nsin(country_code: &str, nsin: &str) -> Result<Isin, IsinError> {
+        let mut isin = format!("{}{}", country_code, nsin);
+        let check_digit = calc_check_digit(&isin)?;
+        isin.push(check_digit);
+        isin.parse()
+    }
+
+    /// Return the two letter country code
+    pub fn country_code(&self) -> &str {
+        &self.as_str()[..2]
+    }
+
+    /// Return the nine character national security identifier
+    pub fn nsin(&self) -> &str {
+        &self.as_str()[2..11]
+    }
+
+    /// Return the check digit, i.e. the last character of the ISIN
+    pub fn check_digit(&self) -> char {
+        self.0[11] as char
+    }
+
+    /// Extract the SEDOL, see [`Sedol::from_isin`]
+    pub fn to_sedol(&self) -> Result<Sedol, FromIsinError> {
+        if IsinCountry::from_code(self.country_code()).is_none() || !self.nsin().starts_with("00") {
+            return Err(FromIsinError::NotSedolBased {
+                input: self.to_string(),
+            });
+        }
+        self.nsin()[2..]
+            .parse()
+            .map_err(FromIsinError::InvalidSedol)
+    }
+}
+
+impl FromStr for Isin {
+    type Err = IsinError;
+
+    fn from_str(isin: &str) -> Result<Self, Self::Err> {
+        check_characters(isin)?;
+        let bytes: [u8; 12] = to_bytes(isin)?;
+        if !bytes[..2].iter().all(u8::is_ascii_uppercase) {
+            return Err(IsinError::InvalidCountryCode {
+                country_code: isin[..2].to_string(),
+                input: isin.to_string(),
+            });
+        }
+        let got_check_digit = bytes[11] as char;
+        let calc_check_digit = luhn_check_digit(&isin.as_bytes()[..11]);
+        if got_check_digit != calc_check_digit {
+            return Err(IsinError::InvalidCheckDigit {
+                got_check_digit,
+                calc_check_digit,
+                input: isin.to_string(),
+            });
+        }
+        Ok(Isin(bytes))
+    }
+}
+
+impl_identifier!(Isin, IsinError, "ISIN");
+
+/// Country prefixes of ISINs that embed a SEDOL, see [`Sedol::to_isin`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IsinCountry {
+    /// United Kingdom
+    GB,
+    /// Ireland
+    IE,
+    /// Guernsey
+    GG,
+    /// Jersey
+    JE,
+    /// Isle of Man
+    IM,
+}
+
+impl IsinCountry {
+    /// All supported countries
+    pub const ALL: [IsinCountry; 5] = [
+        IsinCountry::GB,
+        IsinCountry::IE,
+        IsinCountry::GG,
+        IsinCountry::JE,
+        IsinCountry::IM,
+    ];
+
+    /// The two letter country code
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IsinCountry::GB => "GB",
+            IsinCountry::IE => "IE",
+            IsinCountry::GG => "GG",
+            IsinCountry::JE => "JE",
+            IsinCountry::IM => "IM",
+        }
+    }
+
+    /// Look up a supported country by its two letter code
+    pub fn from_code(code: &str) -> Option<IsinCountry> {
+        IsinCountry::ALL
+            .into_iter()
+            .find(|country| country.as_str() == code)
+    }
+}
+
+impl fmt::Display for IsinCountry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Sedol {
+    /// Convert to the ISIN for the country: the country code, `00`, the SEDOL and the ISIN
+    /// check digit.
+    ///
+    /// ```
+    /// use sedol::{IsinCountry, Sedol};
+    ///
+    /// let sedol: Sedol = "0263494".parse().unwrap();
+    /// assert_eq!("GB0002634946", sedol.to_isin(IsinCountry::GB).as_str());
+    /// ```
+    pub fn to_isin(&self, country: IsinCountry) -> Isin {
+        let mut bytes = [0; 12];
+        bytes[..2].copy_from_slice(country.as_str().as_bytes());
+        bytes[2..4].copy_from_slice(b"00");
+        bytes[4..11].copy_from_slice(self.as_bytes());
+        bytes[11] = luhn_check_digit(&bytes[..11]) as u8;
+        Isin(bytes)
+    }
+
+    /// Extract the SEDOL from a GB, IE, GG, JE or IM ISIN.
+    ///
+    /// Both the ISIN check digit and the SEDOL check digit are verified. ISINs of other
+    /// countries, or without the `00` before the SEDOL, are not SEDOL-based and are rejected
+    /// with [`FromIsinError::NotSedolBased`].
+    ///
+    /// ```
+    /// use sedol::{FromIsinError, Sedol};
+    ///
+    /// let sedol = Sedol::from_isin("IE00B4BNMY34").unwrap();
+    /// assert_eq!("B4BNMY3", sedol.as_str());
+    /// assert!(matches!(
+    ///     Sedol::from_isin("US0378331005"),
+    ///     Err(FromIsinError::NotSedolBased { .. })
+    /// ));
+    /// ```
+    pub fn from_isin(isin: &str) -> Result<Sedol, FromIsinError> {
+        validate(isin)
+            .map_err(FromIsinError::InvalidIsin)?
+            .to_sedol()
+    }
+}
+
+/// Check that every char is a digit 0-9 or an uppercase letter A-Z
+fn check_characters(isin: &str) -> Result<(), IsinError> {
+    identifier::check_characters(isin, |c| c.is_ascii_digit() || c.is_ascii_uppercase())
+}
+
+/// Calculate the Luhn check digit over the letter-expanded digits of the input.
+///
+/// The input must be ASCII digits and uppercase letters.
+fn luhn_check_digit(isin: &[u8]) -> char {
+    let mut digits = Vec::with_capacity(2 * isin.len());
+    for &byte in isin {
+        let value = match byte {
+            b'0'..=b'9' => byte - b'0',
+            _ => byte.saturating_sub(b'A') + 10,
+        };
+        if value >= 10 {
+            digits.push(value / 10);
+        }
+        digits.push(value % 10);
+    }
+    // The check digit will be appended, so the rightmost digit here is doubled
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &digit)| {
+            let digit = u32::from(digit);
+            if i % 2 == 0 {
+                digit * 2 / 10 + digit * 2 % 10
+            } else {
+                digit
+            }
+        })
+        .sum();
+    (b'0' + ((10 - sum % 10) % 10) as u8) as char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SedolError;
+
+    #[test]
+    fn check_digit() {
+        assert_eq!(Ok('5'), calc_check_digit("US037833100"));
+        assert_eq!(Ok('6'), calc_check_digit("GB000263494"));
+        assert_eq!(Ok('4'), calc_check_digit("IE00B4BNMY34"));
+        assert_eq!(Ok('3'), calc_check_digit("AU0000XVGZA"));
+        assert!(matches!(
+            calc_check_digit("US03783310"),
+            Err(IsinError::InvalidLength { length: 10, .. })
+        ));
+    }
+
+    #[test]
+    fn valid() {
+        for isin in [
+            "US0378331005",
+            "AU0000XVGZA3",
+            "GB0002634946",
+            "JP3946600008",
+        ] {
+            assert_eq!(isin, validate(isin).unwrap().as_str());
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Err(IsinError::InvalidCharacter {
+                character: 'u',
+                position: 0,
+                input: "us0378331005".to_string()
+            }),
+            validate("us0378331005")
+        );
+        assert_eq!(
+            Err(IsinError::InvalidLength {
+                length: 11,
+                input: "US037833100".to_string()
+            }),
+            validate("US037833100")
+        );
+        assert_eq!(
+            Err(IsinError::InvalidCountryCode {
+                country_code: "1S".to_string(),
+                input: "1S0378331005".to_string()
+            }),
+            validate("1S0378331005")
+        );
+        assert_eq!(
+            Err(IsinError::InvalidCheckDigit {
+                got_check_digit: '6',
+                calc_check_digit: '5',
+                input: "US0378331006".to_string()
+            }),
+            validate("US0378331006")
+        );
+    }
+
+    #[test]
+    fn to_isin() {
+        let sedol: Sedol = "B4BNMY3".parse().unwrap();
+        assert_eq!("IE00B4BNMY34", sedol.to_isin(IsinCountry::IE).as_str());
+        for country in IsinCountry::ALL {
+            assert_eq!(Ok(sedol), Sedol::from_isin(sedol.to_isin(country).as_str()));
+        }
+    }
+
+    #[test]
+    fn from_isin_invalid() {
+        assert!(matches!(
+            Sedol::from_isin("GB000263494"),
+            Err(FromIsinError::InvalidIsin(IsinError::InvalidLength {
+                length: 11,
+                ..
+            }))
+        ));
+        assert!(matches!(
+            Sedol::from_isin("GB0002634947"),
+            Err(FromIsinError::InvalidIsin(
+                IsinError::InvalidCheckDigit { .. }
+            ))
+        ));
+        let mut isin = String::from("GB100263494");
+        isin.push(luhn_check_digit(isin.as_bytes()));
+        assert!(matches!(
+            Sedol::from_isin(&isin),
+            Err(FromIsinError::NotSedolBased { .. })
+        ));
+        let mut isin = String::from("GB000263495");
+        isin.push(luhn_check_digit(isin.as_bytes()));
+        assert!(matches!(
+            Sedol::from_isin(&isin),
+            Err(FromIsinError::InvalidSedol(
+                SedolError::InvalidCheckDigit { .. }
+            ))
+        ));
+    }
+}
@@ -0,0 +1,175 @@
+//! LEI (ISO 17442)
+//!
+//! A Legal Entity Identifier is a four character prefix identifying the issuing Local
+//! Operating Unit (LOU), a fourteen character entity-specific part and two check digits
+//! calculated with ISO 7064 MOD 97-10.
+//!
+//! <https://en.wikipedia.org/wiki/Legal_Entity_Identifier>
+//!
+//! # Examples
+//! ```
+//! use sedol::lei;
+//!
+//! let lei = lei::validate("HWUPKR0MPOU8FGXBT394").unwrap();
+//! assert_eq!("HWUP", lei.lou_prefix());
+//! assert_eq!("KR0MPOU8FGXBT3", lei.entity());
+//! assert_eq!("94", lei.check_digits());
+//! assert_eq!(Ok("94".to_string()), lei::calc_check_digits("HWUPKR0MPOU8FGXBT3"));
+//! ```
+
+use std::{fmt, str::FromStr};
+
+pub use crate::errors::LeiError;
+use crate::identifier::{self, check_length, impl_identifier, to_bytes};
+
+/// Check if the LEI is valid.
+///
+/// We do the checks in the following order:
+/// 1. only digits 0-9 and uppercase letters A-Z are present
+/// 2. the length of the string is 20
+/// 3. compute and compare the check digits
+pub fn validate(lei: &str) -> Result<Lei, LeiError> {
+    lei.parse()
+}
+
+/// Calculate the two ISO 7064 MOD 97-10 check digits for an LEI.
+///
+/// The input must be 18 characters long, or 20 characters long in which case the last two
+/// characters are taken to be existing check digits and are ignored.
+///
+/// Letters are expanded to two digits (A=10 to Z=35) and `00` is appended. The check digits
+/// are 98 minus the remainder of that number divided by 97, so that a valid LEI is 1 modulo 97.
+pub fn calc_check_digits(lei: &str) -> Result<String, LeiError> {
+    check_characters(lei)?;
+    check_length(lei, &[18, 20])?;
+    Ok(mod_97_10(&lei.as_bytes()[..18]))
+}
+
+/// A validated LEI, stored inline as its twenty ASCII bytes.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lei([u8; 20]);
+
+impl Lei {
+    /// Return the four character prefix of the LOU that issued the LEI
+    pub fn lou_prefix(&self) -> &str {
+        &self.as_str()[..4]
+    }
+
+    /// Return the fourteen character entity-specific part
+    pub fn entity(&self) -> &str {
+        &self.as_str()[4..18]
+    }
+
+    /// Return the two check digits
+    pub fn check_digits(&self) -> &str {
+        &self.as_str()[18..]
+    }
+}
+
+impl FromStr for Lei {
+    type Err = LeiError;
+
+    fn from_str(lei: &str) -> Result<Self, Self::Err> {
+        check_characters(lei)?;
+        let bytes: [u8; 20] = to_bytes(lei)?;
+        let calc_check_digits = mod_97_10(&bytes[..18]);
+        if lei[18..] != calc_check_digits {
+            return Err(LeiError::InvalidCheckDigits {
+                got_check_digits: lei[18..].to_string(),
+                calc_check_digits,
+                input: lei.to_string(),
+            });
+        }
+        Ok(Lei(bytes))
+    }
+}
+
+impl_identifier!(Lei, LeiError, "LEI");
+
+/// Check that every char is a digit 0-9 or an uppercase letter A-Z
+fn check_characters(lei: &str) -> Result<(), LeiError> {
+    identifier::check_characters(lei, |c| c.is_ascii_digit() || c.is_ascii_uppercase())
+}
+
+/// Calculate the MOD 97-10 check digits for the first 18 chars. The input must be valid chars.
+fn mod_97_10(lei: &[u8]) -> String {
+    // Reduce as we go, so the letter-expanded number never has to be held in full
+    let mut remainder: u32 = 0;
+    for &byte in lei {
+        remainder = match byte {
+            b'0'..=b'9' => (remainder * 10 + u32::from(byte - b'0')) % 97,
+            _ => (remainder * 100 + u32::from(byte.saturating_sub(b'A')) + 10) % 97,
+        };
+    }
+    // Append the "00" placeholder for the check digits
+    remainder = remainder * 100 % 97;
+    format!("{:02}", 98 - remainder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        for lei in [
+            "HWUPKR0MPOU8FGXBT394",
+            "7LTWFZYICNSX8D621K86",
+            "5493001KJTIIGC8Y1R12",
+            "213800D1EI4B9WTWWD28",
+        ] {
+            assert_eq!(lei, validate(lei).unwrap().as_str());
+        }
+    }
+
+    #[test]
+    fn check_digits() {
+        assert_eq!(
+            Ok("86".to_string()),
+            calc_check_digits("7LTWFZYICNSX8D621K")
+        );
+        assert_eq!(
+            Ok("12".to_string()),
+            calc_check_digits("5493001KJTIIGC8Y1R99")
+        );
+        assert!(matches!(
+            calc_check_digits("5493001KJTIIGC8Y1"),
+            Err(LeiError::InvalidLength { length: 17, .. })
+        ));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Err(LeiError::InvalidCharacter {
+                character: '-',
+                position: 4,
+                input: "HWUP-KR0MPOU8FGXBT39".to_string()
+            }),
+            validate("HWUP-KR0MPOU8FGXBT39")
+        );
+        assert_eq!(
+            Err(LeiError::InvalidLength {
+                length: 18,
+                input: "HWUPKR0MPOU8FGXBT3".to_string()
+            }),
+            validate("HWUPKR0MPOU8FGXBT3")
+        );
+        assert_eq!(
+            Err(LeiError::InvalidCheckDigits {
+                got_check_digits: "49".to_string(),
+                calc_check_digits: "94".to_string(),
+                input: "HWUPKR0MPOU8FGXBT349".to_string()
+            }),
+            validate("HWUPKR0MPOU8FGXBT349")
+        );
+    }
+
+    #[test]
+    fn parts() {
+        let lei = validate("5493001KJTIIGC8Y1R12").unwrap();
+        assert_eq!("5493", lei.lou_prefix());
+        assert_eq!("001KJTIIGC8Y1R", lei.entity());
+        assert_eq!("12", lei.check_digits());
+    }
+}
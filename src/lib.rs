@@ -32,15 +32,51 @@
 
 #![warn(missing_docs)]
 
+mod clean;
+mod compact;
+pub mod cusip;
 mod errors;
-pub use errors::SedolError;
+pub mod figi;
+mod format;
+mod identifier;
+pub mod isin;
+pub mod lei;
+mod messages;
+mod ocr;
+mod partial;
+mod rank;
+mod recover;
+mod sedol;
+mod sedol_char;
+mod set;
+mod suggest;
+mod trace;
+mod unicode;
+mod validator;
+pub use clean::{clean_with_report, CleanEdit, CleanOptions, CleanReport};
+pub use errors::{FromIsinError, SedolError, SedolErrorKind};
+pub use format::{LegacySedol, ModernSedol, SedolFormat};
+pub use isin::IsinCountry;
+pub use messages::{register_catalog, Catalog};
+pub use ocr::{ocr_candidates, OcrCandidate, OcrSubstitution};
+pub use partial::PartialSedol;
+pub use rank::{rank, unrank, TOTAL_VALID};
+pub use recover::recover_numeric;
+pub use sedol::{Sedol, SedolStr};
+pub use sedol_char::SedolChar;
+pub use set::SedolSet;
+pub use suggest::{suggest_corrections, Correction, Edit};
+pub use trace::{explain_check_digit, CheckDigitStep, CheckDigitTrace};
+pub use unicode::{clean_unicode, fold_to_ascii, UnicodeFold};
+pub use validator::Validator;
 
 /// Remove all characters except is_ascii_alphabetic and is_ascii_digit
+///
+/// Use [`clean_with_report`] to find out what was removed.
 pub fn clean(sedol: &str) -> String {
-    sedol.replace(
-        |x: char| !x.is_ascii_alphabetic() && !x.is_ascii_digit(),
-        "",
-    )
+    clean_with_report(sedol, CleanOptions::new())
+        .cleaned
+        .into_owned()
 }
 
 /// Check if the SEDOL is valid.
@@ -50,42 +86,131 @@ pub fn clean(sedol: &str) -> String {
 /// 2. the length of the string is 7
 /// 3. all characters are digits if the first char is a digit
 /// 4. compute and compare the check digit
-pub fn validate(sedol: &str) -> Result<&str, SedolError> {
-    let allowed_characters = "0123456789BCDFGHJKLMNPQRSTVWXYZ";
-    for character in sedol.chars() {
-        if !allowed_characters.contains(character) {
-            return Err(SedolError::InvalidCharacter { character });
+///
+/// The returned [`SedolStr`] borrows from the input; call `to_owned` on it to get a [`Sedol`].
+pub fn validate(sedol: &str) -> Result<&SedolStr, SedolError> {
+    SedolStr::new(sedol)
+}
+
+/// Check the SEDOL against every rule and return all violations instead of the first one.
+///
+/// [`validate`] stops at the first problem. `diagnose` reports, in this order:
+/// 1. every invalid character
+/// 2. a wrong length
+/// 3. every non-digit if the first char is a digit
+/// 4. a check digit mismatch, if the length is 7 and all seven chars are valid, so an
+///    invalid check digit char is only reported as an invalid character
+///
+/// An empty `Vec` means the SEDOL is valid.
+///
+/// ```
+/// let errors = sedol::diagnose("a15kx");
+/// assert_eq!(4, errors.len());
+/// assert!(sedol::diagnose("B15KXQ8").is_empty());
+/// ```
+pub fn diagnose(sedol: &str) -> Vec<SedolError> {
+    let mut errors = Vec::new();
+    let chars: Vec<char> = sedol.chars().collect();
+    for (position, &character) in chars.iter().enumerate() {
+        if !is_allowed(character) {
+            errors.push(SedolError::InvalidCharacter {
+                character,
+                position,
+                input: sedol.to_string(),
+            });
         }
     }
-    if sedol.len() != 7 {
-        return Err(SedolError::InvalidLength);
+    if chars.len() != 7 {
+        errors.push(SedolError::InvalidLength {
+            length: chars.len(),
+            input: sedol.to_string(),
+        });
     }
-    if sedol.chars().next().unwrap().is_numeric() && !sedol.bytes().all(|c| c.is_ascii_digit()) {
-        return Err(SedolError::InvalidOldFormat);
+    if chars.first().is_some_and(char::is_ascii_digit) {
+        for (position, &character) in chars.iter().enumerate() {
+            if is_allowed(character) && !character.is_ascii_digit() {
+                errors.push(SedolError::InvalidOldFormat {
+                    character,
+                    position,
+                    input: sedol.to_string(),
+                });
+            }
+        }
     }
-    let got_check_digit = sedol.chars().rev().next().unwrap();
-    let calc_check_digit = calc_check_digit(sedol);
-
-    if got_check_digit != calc_check_digit {
-        return Err(SedolError::InvalidCheckDigit {
-            got_check_digit,
-            calc_check_digit,
-        });
+    if chars.len() == 7 && chars.iter().all(|&c| is_allowed(c)) {
+        let got_check_digit = chars[6];
+        let calc_check_digit = calc_check_digit(sedol);
+        if got_check_digit != calc_check_digit {
+            errors.push(SedolError::InvalidCheckDigit {
+                got_check_digit,
+                calc_check_digit,
+                position: 6,
+                input: sedol.to_string(),
+            });
+        }
     }
-    return Ok(sedol);
+    errors
 }
 
 /// Calculate the check digits for the sedol
+///
+/// Only the first six characters are used. This function never panics, but characters
+/// outside the SEDOL alphabet are ignored and inputs shorter than six characters give a
+/// meaningless result; use [`try_calc_check_digit`] for input that has not been validated.
 pub fn calc_check_digit(sedol: &str) -> char {
-    let weights = [1, 3, 1, 7, 3, 9];
-    let allowed_characters = "0123456789 BCD FGH JKLMN PQRST VWXYZ"; // spaces are important for indexing
-    let s: usize = weights
+    let s: usize = WEIGHTS
         .iter()
         .zip(sedol.chars())
-        .map(|(x, y)| x * allowed_characters.chars().position(|c| c == y).unwrap())
+        .map(|(x, y)| x * char_value(y).unwrap_or(0))
         .sum();
-    let check_digit = char::from_digit((10 - s as u32 % 10) % 10, 10).unwrap();
-    check_digit
+    check_digit_from_sum(s)
+}
+
+/// Calculate the check digit for the sedol, rejecting input that is not a SEDOL base.
+///
+/// The input must be 6 characters long, or 7 characters long in which case the last
+/// character is taken to be an existing check digit and is ignored. All characters must be
+/// digits 0-9 or letters B-Z (excluding vowels).
+///
+/// ```
+/// assert_eq!(Ok('7'), sedol::try_calc_check_digit("BD9MZZ"));
+/// assert!(sedol::try_calc_check_digit("bd9mzz").is_err());
+/// ```
+pub fn try_calc_check_digit(sedol: &str) -> Result<char, SedolError> {
+    for (position, character) in sedol.chars().enumerate() {
+        if char_value(character).is_none() {
+            return Err(SedolError::InvalidCharacter {
+                character,
+                position,
+                input: sedol.to_string(),
+            });
+        }
+    }
+    if sedol.len() != 6 && sedol.len() != 7 {
+        return Err(SedolError::InvalidLength {
+            length: sedol.len(),
+            input: sedol.to_string(),
+        });
+    }
+    Ok(calc_check_digit(sedol))
+}
+
+/// Weights applied to the first six characters in the check digit calculation
+const WEIGHTS: [usize; 6] = [1, 3, 1, 7, 3, 9];
+
+/// Look up the value of a SEDOL character used in the check digit calculation
+fn char_value(character: char) -> Option<usize> {
+    SedolChar::lookup_char(character).map(|c| usize::from(c.value()))
+}
+
+/// Check that the char is a digit 0-9 or a letter B-Z (excluding vowels)
+fn is_allowed(character: char) -> bool {
+    SedolChar::lookup_char(character).is_some()
+}
+
+/// Turn the weighted sum into the check digit character
+fn check_digit_from_sum(sum: usize) -> char {
+    (b'0' + ((10 - sum % 10) % 10) as u8) as char
 }
 
 #[cfg(test)]
@@ -95,7 +220,11 @@ mod tests {
     #[test]
     fn invalid_character() {
         assert_eq!(
-            Err(SedolError::InvalidCharacter { character: 'A' }),
+            Err(SedolError::InvalidCharacter {
+                character: 'A',
+                position: 0,
+                input: "A15KXQ8".to_string()
+            }),
             validate("A15KXQ8")
         );
     }
@@ -112,14 +241,23 @@ mod tests {
 
     #[test]
     fn invalid_new_format() {
-        assert_eq!(Err(SedolError::InvalidOldFormat), validate("015KXQ8"));
+        assert_eq!(
+            Err(SedolError::InvalidOldFormat {
+                character: 'K',
+                position: 3,
+                input: "015KXQ8".to_string()
+            }),
+            validate("015KXQ8")
+        );
     }
     #[test]
     fn invalid_checkdigit() {
         assert_eq!(
             Err(SedolError::InvalidCheckDigit {
                 got_check_digit: '7',
-                calc_check_digit: '8'
+                calc_check_digit: '8',
+                position: 6,
+                input: "B15KXQ7".to_string()
             }),
             validate("B15KXQ7")
         );
@@ -141,7 +279,9 @@ mod tests {
         assert_eq!(
             Err(SedolError::InvalidCheckDigit {
                 got_check_digit: '6',
-                calc_check_digit: '7'
+                calc_check_digit: '7',
+                position: 6,
+                input: "BD9MZZ6".to_string()
             }),
             validate(&clean("BD-9MZ-Z6??!!  "))
         );
@@ -151,7 +291,7 @@ mod tests {
     fn test_error_format() {
         let invalid_sedol_string = "BD9MZZ6";
         match validate(invalid_sedol_string) {
-            Err(e) => assert_eq!("invalid check digit 6, expected 7", format!("{}",e)),
+            Err(e) => assert_eq!("invalid check digit 6, expected 7", format!("{}", e)),
             _ => panic!(),
         }
     }
@@ -160,7 +300,10 @@ mod tests {
     fn test_error_format_invalid_old() {
         let invalid_sedol_string = "0D9MZZ6";
         match validate(invalid_sedol_string) {
-            Err(e) => assert_eq!("invalid format, expected all digits when first char is digit", format!("{}",e)),
+            Err(e) => assert_eq!(
+                "invalid format, expected all digits when first char is digit",
+                format!("{}", e)
+            ),
             _ => panic!(),
         }
     }
@@ -169,7 +312,7 @@ mod tests {
     fn test_error_format_invalid_length() {
         let invalid_sedol_string = "0D9MZZ";
         match validate(invalid_sedol_string) {
-            Err(e) => assert_eq!("invalid length, expected 7", format!("{}",e)),
+            Err(e) => assert_eq!("invalid length, expected 7", format!("{}", e)),
             _ => panic!(),
         }
     }
@@ -178,8 +321,170 @@ mod tests {
     fn test_error_format_invalid_char() {
         let invalid_sedol_string = "!D9MZZ";
         match validate(invalid_sedol_string) {
-            Err(e) => assert_eq!("invalid character !", format!("{}",e)),
+            Err(e) => assert_eq!("invalid character !", format!("{}", e)),
             _ => panic!(),
         }
     }
+
+    #[test]
+    fn try_calc_check_digit_valid() {
+        assert_eq!(Ok('7'), try_calc_check_digit("BD9MZZ"));
+        assert_eq!(Ok('7'), try_calc_check_digit("BD9MZZ6"));
+        assert_eq!(Ok('5'), try_calc_check_digit("595413"));
+    }
+
+    #[test]
+    fn try_calc_check_digit_invalid() {
+        assert_eq!(
+            Err(SedolError::InvalidCharacter {
+                character: 'a',
+                position: 3,
+                input: "BD9aZZ".to_string()
+            }),
+            try_calc_check_digit("BD9aZZ")
+        );
+        assert_eq!(
+            Err(SedolError::InvalidCharacter {
+                character: ' ',
+                position: 1,
+                input: "B D9MZZ".to_string()
+            }),
+            try_calc_check_digit("B D9MZZ")
+        );
+        assert_eq!(
+            Err(SedolError::InvalidLength {
+                length: 5,
+                input: "BD9MZ".to_string()
+            }),
+            try_calc_check_digit("BD9MZ")
+        );
+        assert_eq!(
+            Err(SedolError::InvalidLength {
+                length: 8,
+                input: "BD9MZZ7B".to_string()
+            }),
+            try_calc_check_digit("BD9MZZ7B")
+        );
+    }
+
+    #[test]
+    fn error_details() {
+        let error = validate("B15KXQ7").unwrap_err();
+        assert_eq!("B15KXQ7", error.input());
+        assert_eq!(Some(6), error.position());
+        let error = validate("5954X35").unwrap_err();
+        assert_eq!(Some(4), error.position());
+        let error = validate("595413").unwrap_err();
+        assert_eq!(None, error.position());
+    }
+
+    #[test]
+    fn error_codes() {
+        assert_eq!("SEDOL001", validate("A15KXQ8").unwrap_err().code());
+        assert_eq!("SEDOL002", validate("B15KXQ").unwrap_err().code());
+        assert_eq!("SEDOL003", validate("015KXQ8").unwrap_err().code());
+        assert_eq!(
+            SedolErrorKind::InvalidCheckDigit,
+            validate("B15KXQ7").unwrap_err().kind()
+        );
+        for kind in SedolErrorKind::ALL {
+            assert_eq!(Some(kind), SedolErrorKind::from_code(kind.code()));
+        }
+        assert_eq!(None, SedolErrorKind::from_code("SEDOL000"));
+    }
+
+    #[test]
+    fn diagnose_all() {
+        let input = "a15kx".to_string();
+        assert_eq!(
+            vec![
+                SedolError::InvalidCharacter {
+                    character: 'a',
+                    position: 0,
+                    input: input.clone()
+                },
+                SedolError::InvalidCharacter {
+                    character: 'k',
+                    position: 3,
+                    input: input.clone()
+                },
+                SedolError::InvalidCharacter {
+                    character: 'x',
+                    position: 4,
+                    input: input.clone()
+                },
+                SedolError::InvalidLength {
+                    length: 5,
+                    input: input.clone()
+                },
+            ],
+            diagnose(&input)
+        );
+    }
+
+    #[test]
+    fn diagnose_old_format_and_check_digit() {
+        let input = "0D9MZZ6".to_string();
+        let errors = diagnose(&input);
+        assert_eq!(5, errors.len());
+        assert_eq!(
+            SedolError::InvalidOldFormat {
+                character: 'D',
+                position: 1,
+                input: input.clone()
+            },
+            errors[0]
+        );
+        assert!(matches!(
+            errors[4],
+            SedolError::InvalidCheckDigit {
+                got_check_digit: '6',
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn diagnose_invalid_check_digit_char() {
+        let input = "BD9MZZA".to_string();
+        assert_eq!(
+            vec![SedolError::InvalidCharacter {
+                character: 'A',
+                position: 6,
+                input: input.clone()
+            }],
+            diagnose(&input)
+        );
+    }
+
+    #[test]
+    fn diagnose_valid() {
+        assert!(diagnose("BD9MZZ7").is_empty());
+        assert!(diagnose("5954135").is_empty());
+    }
+
+    /// Every public function must return rather than panic, whatever the input.
+    #[test]
+    fn no_panic_on_any_byte() {
+        for byte in 0..=u8::MAX {
+            let character = byte as char;
+            for len in 0..=8 {
+                for position in 0..len.max(1) {
+                    let mut input: String = "BD9MZZ7B".chars().take(len).collect();
+                    if position < len {
+                        input.replace_range(position..position + 1, &character.to_string());
+                    } else {
+                        input.push(character);
+                    }
+                    let _ = clean(&input);
+                    let _ = validate(&input);
+                    let _ = calc_check_digit(&input);
+                    let _ = try_calc_check_digit(&input);
+                    let _ = input.parse::<Sedol>();
+                    let _ = validate(&clean(&input));
+                    let _ = diagnose(&input);
+                }
+            }
+        }
+    }
 }
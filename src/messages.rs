@@ -0,0 +1,244 @@
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, PoisonError, RwLock},
+};
+
+use crate::{SedolError, SedolErrorKind};
+
+/// Message templates for [`SedolError`], keyed by [`SedolErrorKind`].
+///
+/// Templates refer to the parameters of [`SedolError::params`] by name in braces, e.g.
+/// `"ungültiges Zeichen {character} an Position {position}"`. Unknown names are left as is.
+///
+/// ```
+/// use sedol::{Catalog, SedolErrorKind};
+///
+/// let german = Catalog::new()
+///     .with(SedolErrorKind::InvalidCheckDigit, "ungültige Prüfziffer {got_check_digit}, erwartet {calc_check_digit}");
+/// sedol::register_catalog("de", german);
+///
+/// let error = sedol::validate("BD9MZZ6").unwrap_err();
+/// assert_eq!("ungültige Prüfziffer 6, erwartet 7", error.localized("de"));
+/// // Kinds and locales without a template fall back to English
+/// assert_eq!("invalid check digit 6, expected 7", error.localized("fr"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Catalog {
+    templates: HashMap<SedolErrorKind, String>,
+}
+
+impl Catalog {
+    /// Create an empty catalog
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The built-in English catalog, matching the `Display` output of [`SedolError`]
+    pub fn english() -> Self {
+        Catalog::new()
+            .with(
+                SedolErrorKind::InvalidCharacter,
+                "invalid character {character}",
+            )
+            .with(SedolErrorKind::InvalidLength, "invalid length, expected 7")
+            .with(
+                SedolErrorKind::InvalidOldFormat,
+                "invalid format, expected all digits when first char is digit",
+            )
+            .with(
+                SedolErrorKind::InvalidCheckDigit,
+                "invalid check digit {got_check_digit}, expected {calc_check_digit}",
+            )
+            .with(
+                SedolErrorKind::UserDefined,
+                "user-defined SEDOL not allowed",
+            )
+            .with(
+                SedolErrorKind::OldFormatNotAllowed,
+                "old format SEDOL not allowed",
+            )
+    }
+
+    /// Set the template for a kind of error
+    pub fn with(mut self, kind: SedolErrorKind, template: &str) -> Self {
+        self.insert(kind, template);
+        self
+    }
+
+    /// Set the template for a kind of error
+    pub fn insert(&mut self, kind: SedolErrorKind, template: &str) {
+        self.templates.insert(kind, template.to_string());
+    }
+
+    /// Render the message for the error, if the catalog has a template for its kind
+    pub fn format(&self, error: &SedolError) -> Option<String> {
+        let template = self.templates.get(&error.kind())?;
+        let params = error.params();
+        // Fill placeholders in one pass so that values, e.g. the input, are never expanded
+        let mut message = String::with_capacity(template.len());
+        let mut rest = template.as_str();
+        while let Some(start) = rest.find('{') {
+            message.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let value = rest.find('}').and_then(|end| {
+                let (_, value) = params.iter().find(|(name, _)| *name == &rest[1..end])?;
+                Some((value, end))
+            });
+            match value {
+                Some((value, end)) => {
+                    message.push_str(value);
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    message.push('{');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        message.push_str(rest);
+        Some(message)
+    }
+}
+
+fn catalogs() -> &'static RwLock<HashMap<String, Catalog>> {
+    static CATALOGS: OnceLock<RwLock<HashMap<String, Catalog>>> = OnceLock::new();
+    CATALOGS.get_or_init(|| {
+        let mut catalogs = HashMap::new();
+        catalogs.insert("en".to_string(), Catalog::english());
+        RwLock::new(catalogs)
+    })
+}
+
+/// Register a catalog for a locale, replacing any catalog previously registered for it.
+///
+/// English is registered as `"en"` by default and is the fallback for
+/// [`SedolError::localized`].
+pub fn register_catalog(locale: &str, catalog: Catalog) {
+    catalogs()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(locale.to_string(), catalog);
+}
+
+impl SedolError {
+    /// Named parameters of the error, as used in [`Catalog`] templates.
+    ///
+    /// Every error has `code` and `input`; the others depend on the kind: `character`,
+    /// `position`, `length`, `got_check_digit` and `calc_check_digit`.
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("code", self.code().to_string()),
+            ("input", self.input().to_string()),
+        ];
+        match self {
+            SedolError::InvalidCharacter {
+                character,
+                position,
+                ..
+            }
+            | SedolError::InvalidOldFormat {
+                character,
+                position,
+                ..
+            } => {
+                params.push(("character", character.to_string()));
+                params.push(("position", position.to_string()));
+            }
+            SedolError::InvalidLength { length, .. } => {
+                params.push(("length", length.to_string()));
+            }
+            SedolError::InvalidCheckDigit {
+                got_check_digit,
+                calc_check_digit,
+                position,
+                ..
+            } => {
+                params.push(("got_check_digit", got_check_digit.to_string()));
+                params.push(("calc_check_digit", calc_check_digit.to_string()));
+                params.push(("position", position.to_string()));
+            }
+            SedolError::UserDefined { position, .. } => {
+                params.push(("position", position.to_string()));
+            }
+            SedolError::OldFormatNotAllowed { .. } => {}
+        }
+        params
+    }
+
+    /// Render the error message from the catalog registered for the locale.
+    ///
+    /// Falls back to the English `Display` output when the locale has no catalog or its
+    /// catalog has no template for this kind of error.
+    pub fn localized(&self, locale: &str) -> String {
+        let catalogs = catalogs().read().unwrap_or_else(PoisonError::into_inner);
+        catalogs
+            .get(locale)
+            .and_then(|catalog| catalog.format(self))
+            .unwrap_or_else(|| self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{diagnose, validate, Validator};
+
+    #[test]
+    fn english_matches_display() {
+        let english = Catalog::english();
+        let mut errors = diagnose("0a5KXQ");
+        errors.extend(diagnose("B15KXQ7"));
+        errors.push(
+            Validator::new()
+                .reject_user_defined(true)
+                .validate("9123458")
+                .unwrap_err(),
+        );
+        errors.push(
+            Validator::new()
+                .allow_old_format(false)
+                .validate("5954135")
+                .unwrap_err(),
+        );
+        for error in errors {
+            assert_eq!(Some(error.to_string()), english.format(&error));
+            assert_eq!(error.to_string(), error.localized("en"));
+        }
+    }
+
+    #[test]
+    fn french() {
+        register_catalog(
+            "fr",
+            Catalog::new().with(
+                SedolErrorKind::InvalidCharacter,
+                "caractère invalide {character} en position {position} ({code})",
+            ),
+        );
+        let error = validate("B1AKXQ8").unwrap_err();
+        assert_eq!(
+            "caractère invalide A en position 2 (SEDOL001)",
+            error.localized("fr")
+        );
+        let error = validate("B15KXQ").unwrap_err();
+        assert_eq!("invalid length, expected 7", error.localized("fr"));
+    }
+
+    #[test]
+    fn placeholders_in_input() {
+        let catalog = Catalog::new().with(
+            SedolErrorKind::InvalidCharacter,
+            "bad {input}: char {character} at {position}{unknown} {",
+        );
+        let error = validate("{position}").unwrap_err();
+        assert_eq!(
+            Some("bad {position}: char { at 0{unknown} {".to_string()),
+            catalog.format(&error)
+        );
+        let error = validate("B{character}").unwrap_err();
+        assert_eq!(
+            Some("bad B{character}: char { at 1{unknown} {".to_string()),
+            catalog.format(&error)
+        );
+    }
+}
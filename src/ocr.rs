@@ -0,0 +1,185 @@
+use crate::Sedol;
+
+/// A char changed by [`ocr_candidates`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OcrSubstitution {
+    /// Zero-based char index in the candidate SEDOL
+    pub position: usize,
+    /// The char in the input
+    pub from: char,
+    /// The char in the candidate
+    pub to: char,
+}
+
+/// A valid SEDOL read from OCR or handwritten input, see [`ocr_candidates`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcrCandidate {
+    /// The candidate SEDOL
+    pub sedol: Sedol,
+    /// The chars that were changed to get the candidate, in order of position
+    pub substitutions: Vec<OcrSubstitution>,
+}
+
+/// Chars that scanned and handwritten input commonly confuses, with the SEDOL chars they may
+/// have been. Vowels can never appear in a SEDOL, so they map only to the digit or consonant
+/// they resemble. Chars that are valid themselves list themselves first.
+fn confusions(character: char) -> Option<&'static [char]> {
+    let alternatives: &'static [char] = match character {
+        'O' | 'o' => &['0'],
+        'I' | 'i' | '|' => &['1'],
+        'l' => &['1', 'L'],
+        'A' | 'a' => &['4'],
+        'E' | 'e' => &['3', 'F'],
+        'U' | 'u' => &['V'],
+        'S' | 's' => &['S', '5'],
+        '5' => &['5', 'S'],
+        'Z' | 'z' => &['Z', '2'],
+        '2' => &['2', 'Z'],
+        'B' => &['B', '8'],
+        '8' => &['8', 'B'],
+        'b' => &['B', '6'],
+        'G' | 'g' => &['G', '6'],
+        '6' => &['6', 'G'],
+        'T' | 't' => &['T', '7'],
+        '7' => &['7', 'T'],
+        _ => return None,
+    };
+    Some(alternatives)
+}
+
+/// Read a SEDOL from OCR or handwritten input, trying every known confusion.
+///
+/// Each char is mapped onto the SEDOL chars it may have been: `O` to `0`, `I` and `l` to
+/// `1`, `S` to `S` or `5`, lowercase letters to uppercase, and so on. Other chars that are
+/// not ASCII letters or digits are removed, like [`clean`](crate::clean) does. Where a char
+/// is ambiguous every alternative is tried, and only the candidates that pass validation
+/// are returned, fewest substitutions first.
+///
+/// This is opt-in: the result is a set of candidates for a human or a reference list to
+/// confirm, not a single answer.
+///
+/// ```
+/// let candidates = sedol::ocr_candidates("B1SKXQ8");
+/// assert_eq!("B15KXQ8", candidates[0].sedol.as_str());
+/// assert_eq!('S', candidates[0].substitutions[0].from);
+///
+/// let candidates = sedol::ocr_candidates("S9S4l3S");
+/// assert_eq!("5954135", candidates[0].sedol.as_str());
+/// ```
+pub fn ocr_candidates(sedol: &str) -> Vec<OcrCandidate> {
+    let mut alternatives: Vec<Vec<char>> = Vec::new();
+    for character in sedol.chars() {
+        if let Some(confused) = confusions(character) {
+            alternatives.push(confused.to_vec());
+        } else if character.is_ascii_alphanumeric() {
+            alternatives.push(vec![character.to_ascii_uppercase()]);
+        }
+    }
+    if alternatives.len() != 7 {
+        return Vec::new();
+    }
+    let originals: Vec<char> = sedol
+        .chars()
+        .filter(|&c| confusions(c).is_some() || c.is_ascii_alphanumeric())
+        .collect();
+
+    let mut candidates = Vec::new();
+    let mut choice = vec![0; alternatives.len()];
+    loop {
+        let candidate: String = choice
+            .iter()
+            .zip(&alternatives)
+            .map(|(&i, alternatives)| alternatives[i])
+            .collect();
+        if let Ok(sedol) = candidate.parse::<Sedol>() {
+            let substitutions = originals
+                .iter()
+                .zip(candidate.chars())
+                .enumerate()
+                .filter(|(_, (&from, to))| from != *to)
+                .map(|(position, (&from, to))| OcrSubstitution { position, from, to })
+                .collect();
+            candidates.push(OcrCandidate {
+                sedol,
+                substitutions,
+            });
+        }
+        // Advance to the next combination, like an odometer
+        let mut position = choice.len();
+        loop {
+            if position == 0 {
+                candidates.sort_by_key(|c: &OcrCandidate| c.substitutions.len());
+                return candidates;
+            }
+            position -= 1;
+            choice[position] += 1;
+            if choice[position] < alternatives[position].len() {
+                break;
+            }
+            choice[position] = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SedolChar;
+
+    #[test]
+    fn confusions_are_allowed_chars() {
+        for character in (0..=u8::MAX).map(char::from) {
+            if let Some(alternatives) = confusions(character) {
+                assert!(alternatives.iter().all(|&c| SedolChar::try_from(c).is_ok()));
+            }
+        }
+    }
+
+    #[test]
+    fn vowels_and_lowercase() {
+        let candidates = ocr_candidates("bd9mzz7");
+        assert_eq!(1, candidates.len());
+        assert_eq!("BD9MZZ7", candidates[0].sedol.as_str());
+        assert_eq!(5, candidates[0].substitutions.len());
+
+        let candidates = ocr_candidates("O263494");
+        assert_eq!("0263494", candidates[0].sedol.as_str());
+        assert_eq!(
+            vec![OcrSubstitution {
+                position: 0,
+                from: 'O',
+                to: '0'
+            }],
+            candidates[0].substitutions
+        );
+    }
+
+    #[test]
+    fn ambiguous() {
+        let candidates = ocr_candidates("BS8S2Z7");
+        let sedols: Vec<&str> = candidates.iter().map(|c| c.sedol.as_str()).collect();
+        assert_eq!(
+            vec!["BS8S227", "BSBS2Z7", "B58SZ27", "B585227", "B5BSZZ7", "B5B52Z7"],
+            sedols
+        );
+        let counts: Vec<usize> = candidates.iter().map(|c| c.substitutions.len()).collect();
+        assert_eq!(vec![1, 1, 3, 3, 3, 3], counts);
+        assert_eq!(
+            vec![OcrSubstitution {
+                position: 5,
+                from: 'Z',
+                to: '2'
+            }],
+            candidates[0].substitutions
+        );
+        let candidates = ocr_candidates("B-1SK XQ8");
+        assert_eq!("B15KXQ8", candidates[0].sedol.as_str());
+        assert_eq!(2, candidates[0].substitutions[0].position);
+    }
+
+    #[test]
+    fn wrong_length() {
+        assert!(ocr_candidates("B15KXQ").is_empty());
+        assert!(ocr_candidates("").is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{calc_check_digit, is_allowed, Sedol, SedolError};
+
+/// Incremental SEDOL validator for validating as the user types.
+///
+/// Chars are pushed one at a time. A push that would make the input invalid is rejected
+/// with the same [`SedolError`] [`validate`](crate::validate) would report, and leaves the
+/// state unchanged, so every state is a valid prefix of some SEDOL.
+///
+/// ```
+/// use sedol::PartialSedol;
+///
+/// let mut partial = PartialSedol::new();
+/// partial.push_str("BD9MZ").unwrap();
+/// assert_eq!(2, partial.remaining());
+/// assert!(partial.push('A').is_err()); // vowels are never allowed
+/// partial.push('Z').unwrap();
+/// assert_eq!(Some('7'), partial.expected_check_digit());
+/// assert!(partial.push('6').is_err());
+/// partial.push('7').unwrap();
+/// assert_eq!("BD9MZZ7", partial.to_sedol().unwrap().as_str());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PartialSedol {
+    chars: [u8; 7],
+    len: usize,
+}
+
+impl PartialSedol {
+    /// Create an empty `PartialSedol`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a char, or report why it cannot follow the chars so far
+    pub fn push(&mut self, character: char) -> Result<(), SedolError> {
+        let position = self.len;
+        let input = || format!("{}{}", self.as_str(), character);
+        if !is_allowed(character) {
+            return Err(SedolError::InvalidCharacter {
+                character,
+                position,
+                input: input(),
+            });
+        }
+        if position == 7 {
+            return Err(SedolError::InvalidLength {
+                length: 8,
+                input: input(),
+            });
+        }
+        if position > 0 && self.chars[0].is_ascii_digit() && !character.is_ascii_digit() {
+            return Err(SedolError::InvalidOldFormat {
+                character,
+                position,
+                input: input(),
+            });
+        }
+        if let Some(calc_check_digit) = self.expected_check_digit() {
+            if character != calc_check_digit {
+                return Err(SedolError::InvalidCheckDigit {
+                    got_check_digit: character,
+                    calc_check_digit,
+                    position,
+                    input: input(),
+                });
+            }
+        }
+        self.chars[position] = character as u8;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Append every char of the string, stopping at the first that is rejected
+    pub fn push_str(&mut self, sedol: &str) -> Result<(), SedolError> {
+        sedol.chars().try_for_each(|character| self.push(character))
+    }
+
+    /// Remove and return the last char, e.g. for backspace
+    pub fn pop(&mut self) -> Option<char> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // Clear the byte so that equal prefixes compare and hash equal
+        let character = std::mem::take(&mut self.chars[self.len]);
+        Some(character as char)
+    }
+
+    /// The chars pushed so far
+    pub fn as_str(&self) -> &str {
+        // SAFETY: only chars from the SEDOL alphabet, which is ASCII, are ever pushed.
+        unsafe { std::str::from_utf8_unchecked(&self.chars[..self.len]) }
+    }
+
+    /// Number of chars pushed so far
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no chars have been pushed
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of chars still needed for a complete SEDOL
+    pub fn remaining(&self) -> usize {
+        7 - self.len
+    }
+
+    /// Whether all 7 chars are present
+    pub fn is_complete(&self) -> bool {
+        self.len == 7
+    }
+
+    /// The check digit expected once the 6 base chars are present
+    pub fn expected_check_digit(&self) -> Option<char> {
+        if self.len < 6 {
+            return None;
+        }
+        Some(calc_check_digit(self.as_str()))
+    }
+
+    /// The SEDOL, once complete
+    pub fn to_sedol(&self) -> Option<Sedol> {
+        if !self.is_complete() {
+            return None;
+        }
+        self.as_str().parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn type_valid() {
+        let mut partial = PartialSedol::new();
+        assert!(partial.is_empty());
+        for (i, character) in "5954135".chars().enumerate() {
+            assert_eq!(7 - i, partial.remaining());
+            assert_eq!(None, partial.to_sedol());
+            partial.push(character).unwrap();
+        }
+        assert!(partial.is_complete());
+        assert_eq!("5954135", partial.to_sedol().unwrap().as_str());
+    }
+
+    #[test]
+    fn reject() {
+        let mut partial = PartialSedol::new();
+        partial.push_str("09").unwrap();
+        assert_eq!(
+            Err(SedolError::InvalidOldFormat {
+                character: 'B',
+                position: 2,
+                input: "09B".to_string()
+            }),
+            partial.push('B')
+        );
+        assert_eq!(
+            Err(SedolError::InvalidCharacter {
+                character: 'b',
+                position: 2,
+                input: "09b".to_string()
+            }),
+            partial.push('b')
+        );
+        assert_eq!("09", partial.as_str());
+    }
+
+    #[test]
+    fn check_digit_and_full() {
+        let mut partial = PartialSedol::new();
+        assert_eq!(
+            Err(SedolError::InvalidCheckDigit {
+                got_check_digit: '7',
+                calc_check_digit: '8',
+                position: 6,
+                input: "B15KXQ7".to_string()
+            }),
+            partial.push_str("B15KXQ7")
+        );
+        assert_eq!("B15KXQ", partial.as_str());
+        assert_eq!(Some('8'), partial.expected_check_digit());
+        partial.push('8').unwrap();
+        assert!(matches!(
+            partial.push('0'),
+            Err(SedolError::InvalidLength { length: 8, .. })
+        ));
+        assert_eq!(Some('8'), partial.pop());
+        assert_eq!(1, partial.remaining());
+    }
+}
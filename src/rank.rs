@@ -0,0 +1,116 @@
+use crate::{check_digit_from_sum, Sedol, SedolChar, SedolFormat, WEIGHTS};
+
+/// Number of valid old format SEDOLs: six digits, `10^6`
+const LEGACY: u32 = 1_000_000;
+
+/// Number of ways to fill the five base chars after a leading letter, `31^5`
+const MODERN_TAIL: u32 = 31 * 31 * 31 * 31 * 31;
+
+/// Number of valid SEDOLs, the size of the range of [`rank`].
+///
+/// `10^6` all-digit SEDOLs, plus 21 leading consonants followed by any five of the 31
+/// alphabet chars. The check digit is derived from the base, so it adds no choices.
+pub const TOTAL_VALID: u32 = LEGACY + 21 * MODERN_TAIL;
+
+/// Map a SEDOL to its position among all valid SEDOLs, in `0..TOTAL_VALID`.
+///
+/// This is a bijection with [`unrank`], so the rank can index a `Vec` or bitmap directly,
+/// or pick SEDOLs uniformly at random. It preserves ordering: all-digit SEDOLs come first,
+/// in numeric order, followed by the alphanumeric ones.
+///
+/// ```
+/// let sedol: sedol::Sedol = "B15KXQ8".parse().unwrap();
+/// let rank = sedol::rank(&sedol);
+/// assert!(rank < sedol::TOTAL_VALID);
+/// assert_eq!(Some(sedol), sedol::unrank(rank));
+/// ```
+pub fn rank(sedol: &Sedol) -> u32 {
+    let indices = sedol
+        .base()
+        .bytes()
+        .filter_map(SedolChar::lookup)
+        .map(|c| u32::from(c.index()));
+    if sedol.format() == SedolFormat::Modern {
+        // Leading consonants have indices 10-30, the other chars are base 31 digits
+        let n = indices.fold(0, |n, index| n * 31 + index);
+        LEGACY + n - 10 * MODERN_TAIL
+    } else {
+        // All digits, whose index is their value
+        indices.fold(0, |n, index| n * 10 + index)
+    }
+}
+
+/// Map a position in `0..TOTAL_VALID` back to the SEDOL with that [`rank`].
+///
+/// Returns `None` for positions outside that range.
+pub fn unrank(rank: u32) -> Option<Sedol> {
+    // Indices into the alphabet of the six base chars
+    let mut indices = [0; 6];
+    if rank < LEGACY {
+        let mut rest = rank;
+        for index in indices.iter_mut().rev() {
+            *index = (rest % 10) as u8;
+            rest /= 10;
+        }
+    } else if rank < TOTAL_VALID {
+        let rank = rank - LEGACY;
+        indices[0] = (rank / MODERN_TAIL) as u8 + 10;
+        let mut rest = rank % MODERN_TAIL;
+        for index in indices[1..].iter_mut().rev() {
+            *index = (rest % 31) as u8;
+            rest /= 31;
+        }
+    } else {
+        return None;
+    }
+    // The base is valid by construction, so build the SEDOL without a string round-trip
+    let mut bytes = [0; 7];
+    let mut sum = 0;
+    for ((byte, &index), weight) in bytes.iter_mut().zip(&indices).zip(WEIGHTS) {
+        let character = SedolChar::from_index(index)?;
+        *byte = character.as_char() as u8;
+        sum += weight * usize::from(character.value());
+    }
+    bytes[6] = check_digit_from_sum(sum) as u8;
+    Some(Sedol::from_valid_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total() {
+        assert_eq!(602_212_171, TOTAL_VALID);
+        assert_eq!(None, unrank(TOTAL_VALID));
+        assert_eq!("ZZZZZZ", unrank(TOTAL_VALID - 1).unwrap().base());
+        assert_eq!("000000", unrank(0).unwrap().base());
+        assert_eq!("999999", unrank(LEGACY - 1).unwrap().base());
+        assert_eq!("B00000", unrank(LEGACY).unwrap().base());
+    }
+
+    #[test]
+    fn bijection_and_order() {
+        let mut previous: Option<Sedol> = None;
+        for n in (0..TOTAL_VALID)
+            .step_by(99_991)
+            .chain(TOTAL_VALID - 100..TOTAL_VALID)
+        {
+            let sedol = unrank(n).unwrap();
+            assert_eq!(Ok(sedol), sedol.as_str().parse());
+            assert_eq!(n, rank(&sedol));
+            if let Some(previous) = previous {
+                assert!(previous < sedol);
+            }
+            previous = Some(sedol);
+        }
+    }
+
+    #[test]
+    fn known() {
+        let sedol: Sedol = "0263494".parse().unwrap();
+        assert_eq!(26349, rank(&sedol));
+        let sedol: Sedol = "B15KXQ8".parse().unwrap();
+        assert_eq!(Some(sedol), unrank(rank(&sedol)));
+    }
+}
@@ -0,0 +1,105 @@
+use crate::Sedol;
+
+/// Recover an old format SEDOL mangled by a spreadsheet.
+///
+/// Excel and CSV round-trips treat all-numeric SEDOLs as numbers, so `0263494` comes back
+/// as `263494`, `263494.0` or `2.63494E+05`. This parses such a number, left-pads it with
+/// zeros to 7 digits and validates the result.
+///
+/// Returns `None` unless the recovery is unambiguous: the input must be a non-negative
+/// integer of at most 7 digits, scientific notation must keep every digit (so `2.63E+05` is
+/// rejected, as the digits after `3` are lost) and the padded result must validate.
+///
+/// ```
+/// assert_eq!("0263494", sedol::recover_numeric("263494").unwrap().as_str());
+/// assert_eq!("0263494", sedol::recover_numeric("263494.0").unwrap().as_str());
+/// assert_eq!("0263494", sedol::recover_numeric("2.63494E+05").unwrap().as_str());
+/// assert_eq!(None, sedol::recover_numeric("2.63E+05"));
+/// ```
+pub fn recover_numeric(sedol: &str) -> Option<Sedol> {
+    let digits = integer_digits(sedol.trim())?;
+    let digits = digits.trim_start_matches('0');
+    if digits.len() > 7 {
+        return None;
+    }
+    format!("{:0>7}", digits).parse().ok()
+}
+
+/// The digits of a non-negative integer written plainly, with a zero fraction or in
+/// scientific notation, or `None` if that is not exactly an integer
+fn integer_digits(number: &str) -> Option<String> {
+    let (mantissa, exponent) = match number.find(['e', 'E']) {
+        Some(index) => {
+            let exponent = number[index + 1..]
+                .strip_prefix('+')
+                .unwrap_or(&number[index + 1..]);
+            if exponent.is_empty() || !exponent.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            (&number[..index], exponent.parse::<usize>().ok()?)
+        }
+        None => (number, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if integer.is_empty() || !(integer.bytes().chain(fraction.bytes())).all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    if exponent > 0 && fraction.len() < exponent {
+        // The digits needed to fill the exponent were lost
+        return None;
+    }
+    let shift = exponent.min(fraction.len());
+    let (moved, rest) = fraction.split_at(shift);
+    if !rest.bytes().all(|b| b == b'0') {
+        return None;
+    }
+    Some(format!("{}{}", integer, moved))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recover() {
+        for input in [
+            "263494",
+            " 263494 ",
+            "0263494",
+            "263494.",
+            "263494.00",
+            "2.63494E+05",
+            "2.63494e5",
+            "26.3494E4",
+            "2.634940E+05",
+        ] {
+            assert_eq!(
+                Some("0263494".to_string()),
+                recover_numeric(input).map(|sedol| sedol.to_string()),
+                "{}",
+                input
+            );
+        }
+        assert_eq!("0001230", recover_numeric("1230").unwrap().as_str());
+    }
+
+    #[test]
+    fn ambiguous_or_invalid() {
+        for input in [
+            "",
+            "263495",
+            "-263494",
+            "263494.5",
+            "2.63E+05",
+            "2.63494E-05",
+            "2.63494E",
+            "263494X",
+            "B15KXQ8",
+            "12345678",
+            ".5",
+        ] {
+            assert_eq!(None, recover_numeric(input), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use std::{
+    borrow::Borrow,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+    str::FromStr,
+};
+
+use crate::{calc_check_digit, is_allowed, SedolError};
+
+/// A validated SEDOL, stored inline as its seven ASCII bytes.
+///
+/// A `Sedol` can only be constructed from a string that passes [`validate`](crate::validate),
+/// so holding one is proof that the value has already been checked.
+///
+/// ```
+/// use sedol::Sedol;
+///
+/// let sedol: Sedol = "BD9MZZ7".parse().unwrap();
+/// assert_eq!("BD9MZZ", sedol.base());
+/// assert_eq!('7', sedol.check_digit());
+/// assert_eq!("BD9MZZ7", sedol.to_string());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Sedol([u8; 7]);
+
+/// A borrowed, validated SEDOL; the unsized counterpart of [`Sedol`].
+///
+/// `SedolStr` relates to [`Sedol`] the way [`str`] relates to [`String`], or
+/// [`Path`](std::path::Path) to [`PathBuf`](std::path::PathBuf). It wraps a `str` that has
+/// already been validated, so fields inside large buffers such as memory-mapped files or
+/// parsed CSV records can be checked in place without allocating or copying.
+///
+/// ```
+/// use sedol::SedolStr;
+///
+/// let record = "BD9MZZ7,Some Holding PLC";
+/// let sedol = SedolStr::new(&record[..7]).unwrap();
+/// assert_eq!("BD9MZZ", sedol.base());
+/// assert_eq!('7', sedol.check_digit());
+/// assert!(sedol.starts_with("BD9"));
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SedolStr(str);
+
+impl SedolStr {
+    /// Validate the string and wrap it as a `SedolStr` without copying.
+    ///
+    /// The checks are the ones documented on [`validate`](crate::validate).
+    pub fn new(sedol: &str) -> Result<&SedolStr, SedolError> {
+        for (position, character) in sedol.chars().enumerate() {
+            if !is_allowed(character) {
+                return Err(SedolError::InvalidCharacter {
+                    character,
+                    position,
+                    input: sedol.to_string(),
+                });
+            }
+        }
+        if sedol.len() != 7 {
+            return Err(SedolError::InvalidLength {
+                length: sedol.len(),
+                input: sedol.to_string(),
+            });
+        }
+        let bytes = sedol.as_bytes();
+        if bytes[0].is_ascii_digit() {
+            if let Some(position) = bytes.iter().position(|c| !c.is_ascii_digit()) {
+                return Err(SedolError::InvalidOldFormat {
+                    character: bytes[position] as char,
+                    position,
+                    input: sedol.to_string(),
+                });
+            }
+        }
+        let got_check_digit = bytes[6] as char;
+        let calc_check_digit = calc_check_digit(sedol);
+
+        if got_check_digit != calc_check_digit {
+            return Err(SedolError::InvalidCheckDigit {
+                got_check_digit,
+                calc_check_digit,
+                position: 6,
+                input: sedol.to_string(),
+            });
+        }
+        Ok(SedolStr::from_str_unchecked(sedol))
+    }
+
+    /// Wrap a string that is already known to be a valid SEDOL
+    fn from_str_unchecked(sedol: &str) -> &SedolStr {
+        // SAFETY: `SedolStr` is `repr(transparent)` over `str`, so the layouts match.
+        unsafe { &*(sedol as *const str as *const SedolStr) }
+    }
+
+    /// Return the SEDOL as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Return the first six characters, i.e. the SEDOL without its check digit
+    pub fn base(&self) -> &str {
+        &self.0[..6]
+    }
+
+    /// Return the check digit, i.e. the last character of the SEDOL
+    pub fn check_digit(&self) -> char {
+        self.0.as_bytes()[6] as char
+    }
+}
+
+impl Deref for SedolStr {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for SedolStr {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ToOwned for SedolStr {
+    type Owned = Sedol;
+
+    fn to_owned(&self) -> Sedol {
+        let mut bytes = [0; 7];
+        bytes.copy_from_slice(self.0.as_bytes());
+        Sedol(bytes)
+    }
+}
+
+impl PartialEq<str> for SedolStr {
+    fn eq(&self, other: &str) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<SedolStr> for str {
+    fn eq(&self, other: &SedolStr) -> bool {
+        self == &other.0
+    }
+}
+
+impl fmt::Display for SedolStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Debug for SedolStr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl Sedol {
+    /// Wrap bytes that are already known to be a valid SEDOL, without checking them again
+    pub(crate) fn from_valid_bytes(bytes: [u8; 7]) -> Sedol {
+        Sedol(bytes)
+    }
+}
+
+impl FromStr for Sedol {
+    type Err = SedolError;
+
+    /// Validate the string and copy it into a `Sedol`.
+    ///
+    /// The checks are the ones documented on [`validate`](crate::validate).
+    fn from_str(sedol: &str) -> Result<Self, Self::Err> {
+        SedolStr::new(sedol).map(ToOwned::to_owned)
+    }
+}
+
+impl TryFrom<&str> for Sedol {
+    type Error = SedolError;
+
+    fn try_from(sedol: &str) -> Result<Self, Self::Error> {
+        sedol.parse()
+    }
+}
+
+impl TryFrom<String> for Sedol {
+    type Error = SedolError;
+
+    fn try_from(sedol: String) -> Result<Self, Self::Error> {
+        sedol.parse()
+    }
+}
+
+impl Deref for Sedol {
+    type Target = SedolStr;
+
+    fn deref(&self) -> &SedolStr {
+        // SAFETY: a `Sedol` is only ever built from a validated string, which is pure ASCII.
+        SedolStr::from_str_unchecked(unsafe { std::str::from_utf8_unchecked(&self.0) })
+    }
+}
+
+impl Borrow<SedolStr> for Sedol {
+    fn borrow(&self) -> &SedolStr {
+        self
+    }
+}
+
+impl AsRef<SedolStr> for Sedol {
+    fn as_ref(&self) -> &SedolStr {
+        self
+    }
+}
+
+impl AsRef<str> for Sedol {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+// Hash the string form so that `Sedol` and its `Borrow` target `SedolStr` hash identically.
+impl Hash for Sedol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl fmt::Display for Sedol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for Sedol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Sedol").field(&self.as_str()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn parse() {
+        let sedol: Sedol = "B15KXQ8".parse().unwrap();
+        assert_eq!("B15KXQ8", sedol.as_str());
+        assert_eq!("B15KXQ", sedol.base());
+        assert_eq!('8', sedol.check_digit());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(
+            Err(SedolError::InvalidLength {
+                length: 6,
+                input: "B15KXQ".to_string()
+            }),
+            "B15KXQ".parse::<Sedol>()
+        );
+        assert_eq!(
+            Err(SedolError::InvalidCheckDigit {
+                got_check_digit: '7',
+                calc_check_digit: '8',
+                position: 6,
+                input: "B15KXQ7".to_string()
+            }),
+            Sedol::try_from("B15KXQ7")
+        );
+    }
+
+    #[test]
+    fn try_from_string() {
+        let sedol = Sedol::try_from(String::from("5954135")).unwrap();
+        assert_eq!("5954135", sedol.as_str());
+    }
+
+    #[test]
+    fn ordering_and_hashing() {
+        let a: Sedol = "5954135".parse().unwrap();
+        let b: Sedol = "B15KXQ8".parse().unwrap();
+        let c: Sedol = "BD9MZZ7".parse().unwrap();
+        assert!(a < b && b < c);
+        let set: HashSet<Sedol> = [a, b, c, a].into_iter().collect();
+        assert_eq!(3, set.len());
+    }
+
+    #[test]
+    fn borrowed() {
+        let buffer = String::from("B15KXQ8|BD9MZZ7");
+        let first = SedolStr::new(&buffer[..7]).unwrap();
+        let second = SedolStr::new(&buffer[8..]).unwrap();
+        assert_eq!("B15KXQ", first.base());
+        assert_eq!('7', second.check_digit());
+        assert_eq!(buffer.as_ptr(), first.as_ptr());
+        assert!(matches!(
+            SedolStr::new(&buffer[..6]),
+            Err(SedolError::InvalidLength { length: 6, .. })
+        ));
+    }
+
+    #[test]
+    fn owned_and_borrowed_interop() {
+        let borrowed = SedolStr::new("BD9MZZ7").unwrap();
+        let owned: Sedol = borrowed.to_owned();
+        assert_eq!(borrowed, &*owned);
+        let set: HashSet<Sedol> = [owned].into_iter().collect();
+        assert!(set.contains(borrowed));
+    }
+
+    #[test]
+    fn format() {
+        let sedol: Sedol = "BD9MZZ7".parse().unwrap();
+        assert_eq!("BD9MZZ7", format!("{}", sedol));
+        assert_eq!("Sedol(\"BD9MZZ7\")", format!("{:?}", sedol));
+    }
+}
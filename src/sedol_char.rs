@@ -0,0 +1,207 @@
+use std::fmt;
+
+use crate::SedolError;
+
+/// A char of the SEDOL alphabet: digits 0-9 and letters B-Z excluding vowels.
+///
+/// This is the single table used by validation and the check digit calculation.
+///
+/// ```
+/// use sedol::SedolChar;
+///
+/// let c = SedolChar::try_from('K').unwrap();
+/// assert_eq!(20, c.value());
+/// assert_eq!(Some(c), SedolChar::from_value(20));
+/// assert!(SedolChar::try_from('E').is_err());
+/// assert_eq!(31, SedolChar::iter().count());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SedolChar(u8);
+
+/// Index in [`SedolChar::ALL`] of every ASCII byte, or `NOT_ALLOWED`. Built from `ALL`.
+const INDEX: [u8; 128] = {
+    let mut index = [NOT_ALLOWED; 128];
+    let mut i = 0;
+    while i < SedolChar::ALL.len() {
+        index[SedolChar::ALL[i].0 as usize] = i as u8;
+        i += 1;
+    }
+    index
+};
+
+const NOT_ALLOWED: u8 = u8::MAX;
+
+impl SedolChar {
+    /// The whole alphabet, in ascending order
+    pub const ALL: [SedolChar; 31] = {
+        let bytes = *b"0123456789BCDFGHJKLMNPQRSTVWXYZ";
+        let mut all = [SedolChar(0); 31];
+        let mut i = 0;
+        while i < 31 {
+            all[i] = SedolChar(bytes[i]);
+            i += 1;
+        }
+        all
+    };
+
+    /// Iterate over the whole alphabet, in ascending order
+    pub fn iter() -> impl Iterator<Item = SedolChar> {
+        SedolChar::ALL.into_iter()
+    }
+
+    /// The value used in the check digit calculation: 0-9 for digits, 10-35 for letters A-Z.
+    ///
+    /// Letters keep their place in the full Latin alphabet, so vowels leave gaps.
+    pub fn value(self) -> u8 {
+        match self.0 {
+            b'0'..=b'9' => self.0 - b'0',
+            _ => self.0 - b'A' + 10,
+        }
+    }
+
+    /// The char with the given check digit value, if it is not a vowel
+    pub fn from_value(value: u8) -> Option<SedolChar> {
+        let byte = match value {
+            0..=9 => b'0' + value,
+            10..=35 => b'A' + value - 10,
+            _ => return None,
+        };
+        SedolChar::lookup(byte)
+    }
+
+    /// The char for the byte, if it is in the alphabet. Unlike `TryFrom`, this does not build
+    /// an error, so it is cheap on the rejecting path.
+    pub(crate) fn lookup(byte: u8) -> Option<SedolChar> {
+        match INDEX.get(usize::from(byte)) {
+            Some(&index) if index != NOT_ALLOWED => Some(SedolChar(byte)),
+            _ => None,
+        }
+    }
+
+    /// The char for the `char`, if it is in the alphabet, see [`SedolChar::lookup`]
+    pub(crate) fn lookup_char(character: char) -> Option<SedolChar> {
+        u8::try_from(character).ok().and_then(SedolChar::lookup)
+    }
+
+    /// Zero-based index of the char in [`SedolChar::ALL`]
+    pub fn index(self) -> u8 {
+        INDEX[usize::from(self.0)]
+    }
+
+    /// The char at the index in [`SedolChar::ALL`]
+    pub fn from_index(index: u8) -> Option<SedolChar> {
+        SedolChar::ALL.get(usize::from(index)).copied()
+    }
+
+    /// The char as a `char`
+    pub fn as_char(self) -> char {
+        self.0 as char
+    }
+
+    /// Whether the char is a digit 0-9
+    pub fn is_digit(self) -> bool {
+        self.0.is_ascii_digit()
+    }
+}
+
+impl TryFrom<u8> for SedolChar {
+    type Error = SedolError;
+
+    fn try_from(byte: u8) -> Result<Self, Self::Error> {
+        match SedolChar::lookup(byte) {
+            Some(character) => Ok(character),
+            None => Err(SedolError::InvalidCharacter {
+                character: byte as char,
+                position: 0,
+                input: (byte as char).to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<char> for SedolChar {
+    type Error = SedolError;
+
+    fn try_from(character: char) -> Result<Self, Self::Error> {
+        match SedolChar::lookup_char(character) {
+            Some(character) => Ok(character),
+            None => Err(SedolError::InvalidCharacter {
+                character,
+                position: 0,
+                input: character.to_string(),
+            }),
+        }
+    }
+}
+
+impl From<SedolChar> for char {
+    fn from(character: SedolChar) -> char {
+        character.as_char()
+    }
+}
+
+impl fmt::Display for SedolChar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.as_char(), f)
+    }
+}
+
+impl fmt::Debug for SedolChar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("SedolChar").field(&self.as_char()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alphabet() {
+        let alphabet: String = SedolChar::iter().map(SedolChar::as_char).collect();
+        assert_eq!("0123456789BCDFGHJKLMNPQRSTVWXYZ", alphabet);
+        for byte in 0..=u8::MAX {
+            let expected = alphabet.as_bytes().contains(&byte);
+            assert_eq!(expected, SedolChar::try_from(byte).is_ok());
+            assert_eq!(expected, SedolChar::try_from(byte as char).is_ok());
+        }
+    }
+
+    #[test]
+    fn values() {
+        let values: Vec<u8> = SedolChar::iter().map(SedolChar::value).collect();
+        assert_eq!(
+            vec![
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 11, 12, 13, 15, 16, 17, 19, 20, 21, 22, 23, 25, 26,
+                27, 28, 29, 31, 32, 33, 34, 35
+            ],
+            values
+        );
+        for character in SedolChar::iter() {
+            assert_eq!(Some(character), SedolChar::from_value(character.value()));
+        }
+        assert_eq!(None, SedolChar::from_value(10));
+        assert_eq!(None, SedolChar::from_value(36));
+    }
+
+    #[test]
+    fn indices() {
+        for (i, character) in SedolChar::iter().enumerate() {
+            assert_eq!(i, usize::from(character.index()));
+            assert_eq!(Some(character), SedolChar::from_index(i as u8));
+        }
+        assert_eq!(None, SedolChar::from_index(31));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Err(SedolError::InvalidCharacter {
+                character: 'Ｂ',
+                position: 0,
+                input: "Ｂ".to_string()
+            }),
+            SedolChar::try_from('Ｂ')
+        );
+    }
+}
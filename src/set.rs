@@ -0,0 +1,612 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use crate::{rank, unrank, Sedol, TOTAL_VALID};
+
+/// Containers with more values than this are stored as bitmaps, others as sorted arrays
+const ARRAY_MAX: usize = 4096;
+
+/// Number of `u64` words in a bitmap container covering `2^16` ranks
+const BITMAP_WORDS: usize = 1024;
+
+/// Magic bytes at the start of a snapshot
+const MAGIC: &[u8; 8] = b"SEDOLSET";
+
+/// Version of the snapshot format written by [`SedolSet::write_to`]
+const VERSION: u16 = 1;
+
+/// The values of one `2^16` block of ranks.
+///
+/// Invariant: never empty, an `Array` holds at most [`ARRAY_MAX`] values in ascending order
+/// and a `Bitmap` holds more, so every set has exactly one representation. A `Bitmap` caches
+/// its number of set bits in `len`.
+#[derive(Clone, PartialEq, Eq)]
+enum Container {
+    Array(Vec<u16>),
+    Bitmap {
+        bits: Box<[u64; BITMAP_WORDS]>,
+        len: usize,
+    },
+}
+
+impl Container {
+    /// Wrap a bitmap, counting its set bits once
+    fn bitmap(bits: Box<[u64; BITMAP_WORDS]>) -> Container {
+        let len = bits.iter().map(|w| w.count_ones() as usize).sum();
+        Container::Bitmap { bits, len }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Container::Array(values) => values.len(),
+            Container::Bitmap { len, .. } => *len,
+        }
+    }
+
+    fn contains(&self, value: u16) -> bool {
+        match self {
+            Container::Array(values) => values.binary_search(&value).is_ok(),
+            Container::Bitmap { bits, .. } => {
+                bits[usize::from(value) / 64] & (1 << (value % 64)) != 0
+            }
+        }
+    }
+
+    /// Iterate over the values in ascending order
+    fn iter(&self) -> Values<'_> {
+        match self {
+            Container::Array(values) => Values::Array(values.iter()),
+            Container::Bitmap { bits, .. } => Values::Bitmap {
+                bits: &bits[..],
+                index: 0,
+                word: bits[0],
+            },
+        }
+    }
+
+    fn insert(&mut self, value: u16) -> bool {
+        match self {
+            Container::Array(values) => match values.binary_search(&value) {
+                Ok(_) => false,
+                Err(index) => {
+                    values.insert(index, value);
+                    if values.len() > ARRAY_MAX {
+                        *self = Container::bitmap(self.to_bitmap());
+                    }
+                    true
+                }
+            },
+            Container::Bitmap { bits, len } => {
+                let word = &mut bits[usize::from(value) / 64];
+                let inserted = *word & (1 << (value % 64)) == 0;
+                *word |= 1 << (value % 64);
+                *len += usize::from(inserted);
+                inserted
+            }
+        }
+    }
+
+    fn remove(&mut self, value: u16) -> bool {
+        let removed = match self {
+            Container::Array(values) => match values.binary_search(&value) {
+                Ok(index) => {
+                    values.remove(index);
+                    true
+                }
+                Err(_) => false,
+            },
+            Container::Bitmap { bits, len } => {
+                let word = &mut bits[usize::from(value) / 64];
+                let removed = *word & (1 << (value % 64)) != 0;
+                *word &= !(1 << (value % 64));
+                *len -= usize::from(removed);
+                removed
+            }
+        };
+        if removed && matches!(self, Container::Bitmap { .. }) && self.len() <= ARRAY_MAX {
+            *self = Container::Array(self.values());
+        }
+        removed
+    }
+
+    fn values(&self) -> Vec<u16> {
+        self.iter().collect()
+    }
+
+    fn to_bitmap(&self) -> Box<[u64; BITMAP_WORDS]> {
+        match self {
+            Container::Array(values) => {
+                let mut bits = Box::new([0; BITMAP_WORDS]);
+                for &value in values {
+                    bits[usize::from(value) / 64] |= 1 << (value % 64);
+                }
+                bits
+            }
+            Container::Bitmap { bits, .. } => bits.clone(),
+        }
+    }
+
+    /// Build a container from a bitmap, as an array if it is sparse
+    fn from_bitmap(bits: Box<[u64; BITMAP_WORDS]>) -> Container {
+        let container = Container::bitmap(bits);
+        if container.len() > ARRAY_MAX {
+            return container;
+        }
+        Container::Array(container.values())
+    }
+
+    /// Build a container from ascending values
+    fn from_sorted(values: Vec<u16>) -> Container {
+        if values.len() > ARRAY_MAX {
+            return Container::bitmap(Container::Array(values).to_bitmap());
+        }
+        Container::Array(values)
+    }
+
+    fn union(&self, other: &Container) -> Container {
+        match (self, other) {
+            (Container::Array(a), Container::Array(b)) => {
+                let mut values = Vec::with_capacity(a.len() + b.len());
+                let (mut i, mut j) = (0, 0);
+                while i < a.len() && j < b.len() {
+                    if a[i] < b[j] {
+                        values.push(a[i]);
+                        i += 1;
+                    } else if b[j] < a[i] {
+                        values.push(b[j]);
+                        j += 1;
+                    } else {
+                        values.push(a[i]);
+                        i += 1;
+                        j += 1;
+                    }
+                }
+                values.extend_from_slice(&a[i..]);
+                values.extend_from_slice(&b[j..]);
+                Container::from_sorted(values)
+            }
+            _ => {
+                let mut bits = self.to_bitmap();
+                for (word, other) in bits.iter_mut().zip(other.to_bitmap().iter()) {
+                    *word |= other;
+                }
+                Container::from_bitmap(bits)
+            }
+        }
+    }
+
+    fn intersection(&self, other: &Container) -> Container {
+        match (self, other) {
+            (Container::Array(values), other) | (other, Container::Array(values)) => {
+                let values = values.iter().copied().filter(|&v| other.contains(v));
+                Container::Array(values.collect())
+            }
+            (Container::Bitmap { bits: a, .. }, Container::Bitmap { bits: b, .. }) => {
+                let mut bits = a.clone();
+                for (word, other) in bits.iter_mut().zip(b.iter()) {
+                    *word &= other;
+                }
+                Container::from_bitmap(bits)
+            }
+        }
+    }
+
+    fn difference(&self, other: &Container) -> Container {
+        match self {
+            Container::Array(values) => {
+                let values = values.iter().copied().filter(|&v| !other.contains(v));
+                Container::Array(values.collect())
+            }
+            Container::Bitmap { bits, .. } => {
+                let mut bits = bits.clone();
+                for (word, other) in bits.iter_mut().zip(other.to_bitmap().iter()) {
+                    *word &= !other;
+                }
+                Container::from_bitmap(bits)
+            }
+        }
+    }
+}
+
+/// Iterator over the values of a [`Container`], in ascending order
+enum Values<'a> {
+    Array(std::slice::Iter<'a, u16>),
+    Bitmap {
+        bits: &'a [u64],
+        /// Index of `word` in `bits`
+        index: usize,
+        /// The bits of `bits[index]` not yet returned
+        word: u64,
+    },
+}
+
+impl Iterator for Values<'_> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        match self {
+            Values::Array(values) => values.next().copied(),
+            Values::Bitmap { bits, index, word } => {
+                while *word == 0 {
+                    *index += 1;
+                    *word = *bits.get(*index)?;
+                }
+                let value = (*index * 64) as u16 + word.trailing_zeros() as u16;
+                *word &= *word - 1;
+                Some(value)
+            }
+        }
+    }
+}
+
+/// A set of SEDOLs, stored compactly by [`rank`].
+///
+/// Ranks are split into blocks of `2^16`. Sparse blocks are stored as sorted arrays of 16 bit
+/// values and dense blocks as bitmaps, so a set takes at most about 2 bytes per SEDOL and
+/// never more than 75 MB, even when it holds every valid SEDOL.
+///
+/// Iteration is in ascending order. Sets can be saved to and loaded from a versioned binary
+/// snapshot file, see [`SedolSet::write_to`].
+///
+/// ```
+/// use sedol::{Sedol, SedolSet};
+///
+/// let a: SedolSet = ["B15KXQ8", "BD9MZZ7"].iter().map(|s| s.parse::<Sedol>().unwrap()).collect();
+/// let b: SedolSet = ["5954135", "BD9MZZ7"].iter().map(|s| s.parse::<Sedol>().unwrap()).collect();
+///
+/// assert_eq!(3, a.union(&b).len());
+/// let both: Vec<String> = a.intersection(&b).iter().map(|s| s.to_string()).collect();
+/// assert_eq!(vec!["BD9MZZ7"], both);
+/// assert!(a.difference(&b).contains(&"B15KXQ8".parse().unwrap()));
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SedolSet {
+    containers: BTreeMap<u16, Container>,
+}
+
+impl SedolSet {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of SEDOLs in the set
+    pub fn len(&self) -> usize {
+        self.containers.values().map(Container::len).sum()
+    }
+
+    /// Whether the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.containers.is_empty()
+    }
+
+    /// Add a SEDOL, returning whether it was not already present
+    pub fn insert(&mut self, sedol: Sedol) -> bool {
+        let (key, value) = split(rank(&sedol));
+        self.containers
+            .entry(key)
+            .or_insert_with(|| Container::Array(Vec::new()))
+            .insert(value)
+    }
+
+    /// Remove a SEDOL, returning whether it was present
+    pub fn remove(&mut self, sedol: &Sedol) -> bool {
+        let (key, value) = split(rank(sedol));
+        let Some(container) = self.containers.get_mut(&key) else {
+            return false;
+        };
+        let removed = container.remove(value);
+        if container.len() == 0 {
+            self.containers.remove(&key);
+        }
+        removed
+    }
+
+    /// Whether the SEDOL is in the set
+    pub fn contains(&self, sedol: &Sedol) -> bool {
+        let (key, value) = split(rank(sedol));
+        self.containers
+            .get(&key)
+            .is_some_and(|container| container.contains(value))
+    }
+
+    /// Iterate over the SEDOLs in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = Sedol> + '_ {
+        self.containers.iter().flat_map(|(&key, container)| {
+            container
+                .iter()
+                .filter_map(move |value| unrank(u32::from(key) << 16 | u32::from(value)))
+        })
+    }
+
+    /// SEDOLs in either set
+    pub fn union(&self, other: &SedolSet) -> SedolSet {
+        let mut containers = self.containers.clone();
+        for (key, container) in &other.containers {
+            let merged = match containers.get(key) {
+                Some(existing) => existing.union(container),
+                None => container.clone(),
+            };
+            containers.insert(*key, merged);
+        }
+        SedolSet { containers }
+    }
+
+    /// SEDOLs in both sets
+    pub fn intersection(&self, other: &SedolSet) -> SedolSet {
+        let containers = self
+            .containers
+            .iter()
+            .filter_map(|(key, container)| {
+                let common = container.intersection(other.containers.get(key)?);
+                (common.len() > 0).then_some((*key, common))
+            })
+            .collect();
+        SedolSet { containers }
+    }
+
+    /// SEDOLs in this set but not in the other
+    pub fn difference(&self, other: &SedolSet) -> SedolSet {
+        let containers = self
+            .containers
+            .iter()
+            .filter_map(|(key, container)| {
+                let rest = match other.containers.get(key) {
+                    Some(other) => container.difference(other),
+                    None => container.clone(),
+                };
+                (rest.len() > 0).then_some((*key, rest))
+            })
+            .collect();
+        SedolSet { containers }
+    }
+
+    /// Write a binary snapshot of the set.
+    ///
+    /// The format is the magic bytes `SEDOLSET`, a little-endian `u16` version (currently 1)
+    /// and a `u32` container count, followed by each container in ascending key order: a
+    /// `u16` key, a `u8` kind, then for kind 0 a `u32` count and that many `u16` values in
+    /// ascending order, or for kind 1 a bitmap of 1024 `u64` words. All integers are
+    /// little-endian. A container holds the ranks whose upper 16 bits are its key.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(self.containers.len() as u32).to_le_bytes())?;
+        for (key, container) in &self.containers {
+            writer.write_all(&key.to_le_bytes())?;
+            match container {
+                Container::Array(values) => {
+                    writer.write_all(&[0])?;
+                    writer.write_all(&(values.len() as u32).to_le_bytes())?;
+                    for value in values {
+                        writer.write_all(&value.to_le_bytes())?;
+                    }
+                }
+                Container::Bitmap { bits, .. } => {
+                    writer.write_all(&[1])?;
+                    for word in bits.iter() {
+                        writer.write_all(&word.to_le_bytes())?;
+                    }
+                }
+            }
+        }
+        writer.flush()
+    }
+
+    /// Read a snapshot written by [`SedolSet::write_to`].
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if the snapshot is malformed, has an unknown
+    /// version or holds a value that is not the rank of a valid SEDOL.
+    pub fn read_from<R: Read>(mut reader: R) -> io::Result<SedolSet> {
+        let mut magic = [0; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid_data("not a SEDOL set snapshot"));
+        }
+        let version = u16::from_le_bytes(read_array(&mut reader)?);
+        if version != VERSION {
+            return Err(invalid_data("unsupported SEDOL set snapshot version"));
+        }
+        let count = u32::from_le_bytes(read_array(&mut reader)?);
+        let mut containers = BTreeMap::new();
+        let mut previous_key = None;
+        for _ in 0..count {
+            let key = u16::from_le_bytes(read_array(&mut reader)?);
+            if previous_key.is_some_and(|previous| previous >= key) {
+                return Err(invalid_data("SEDOL set snapshot keys out of order"));
+            }
+            previous_key = Some(key);
+            let [kind] = read_array(&mut reader)?;
+            let container = match kind {
+                0 => {
+                    let len = u32::from_le_bytes(read_array(&mut reader)?) as usize;
+                    if len == 0 || len > ARRAY_MAX {
+                        return Err(invalid_data("invalid SEDOL set snapshot array length"));
+                    }
+                    let mut values = Vec::with_capacity(len);
+                    for _ in 0..len {
+                        values.push(u16::from_le_bytes(read_array(&mut reader)?));
+                    }
+                    if values.windows(2).any(|pair| pair[0] >= pair[1]) {
+                        return Err(invalid_data("SEDOL set snapshot values out of order"));
+                    }
+                    Container::Array(values)
+                }
+                1 => {
+                    let mut bits = Box::new([0; BITMAP_WORDS]);
+                    for word in bits.iter_mut() {
+                        *word = u64::from_le_bytes(read_array(&mut reader)?);
+                    }
+                    let container = Container::bitmap(bits);
+                    if container.len() <= ARRAY_MAX {
+                        return Err(invalid_data("invalid SEDOL set snapshot bitmap length"));
+                    }
+                    container
+                }
+                _ => return Err(invalid_data("unknown SEDOL set snapshot container kind")),
+            };
+            let last = container.iter().last().unwrap_or(0);
+            if u32::from(key) << 16 | u32::from(last) >= TOTAL_VALID {
+                return Err(invalid_data("SEDOL set snapshot value out of range"));
+            }
+            containers.insert(key, container);
+        }
+        Ok(SedolSet { containers })
+    }
+
+    /// Save a snapshot to a file, see [`SedolSet::write_to`]
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_to(BufWriter::new(File::create(path)?))
+    }
+
+    /// Load a snapshot from a file, see [`SedolSet::read_from`]
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<SedolSet> {
+        SedolSet::read_from(BufReader::new(File::open(path)?))
+    }
+}
+
+/// Split a rank into its container key and the value within the container
+fn split(rank: u32) -> (u16, u16) {
+    ((rank >> 16) as u16, rank as u16)
+}
+
+fn read_array<const N: usize, R: Read>(reader: &mut R) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+impl FromIterator<Sedol> for SedolSet {
+    fn from_iter<I: IntoIterator<Item = Sedol>>(iter: I) -> Self {
+        let mut set = SedolSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Sedol> for SedolSet {
+    fn extend<I: IntoIterator<Item = Sedol>>(&mut self, iter: I) {
+        for sedol in iter {
+            self.insert(sedol);
+        }
+    }
+}
+
+impl fmt::Debug for SedolSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: u32, end: u32) -> SedolSet {
+        (start..end).filter_map(unrank).collect()
+    }
+
+    #[test]
+    fn insert_contains_remove() {
+        let mut set = SedolSet::new();
+        let sedol: Sedol = "B15KXQ8".parse().unwrap();
+        assert!(set.insert(sedol));
+        assert!(!set.insert(sedol));
+        assert!(set.contains(&sedol));
+        assert!(!set.contains(&"BD9MZZ7".parse().unwrap()));
+        assert_eq!(1, set.len());
+        assert!(set.remove(&sedol));
+        assert!(!set.remove(&sedol));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn sorted_iteration() {
+        let sedols: Vec<Sedol> = ["BD9MZZ7", "0263494", "B15KXQ8", "5954135"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect();
+        let set: SedolSet = sedols.iter().copied().collect();
+        let mut sorted = sedols;
+        sorted.sort();
+        assert_eq!(sorted, set.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn dense_and_sparse() {
+        let mut set = range(0, 10_000);
+        assert!(matches!(set.containers[&0], Container::Bitmap { .. }));
+        assert_eq!(10_000, set.len());
+        for n in 100..5_000 {
+            set.remove(&unrank(n).unwrap());
+        }
+        assert!(!set.insert(unrank(0).unwrap()));
+        assert!(!set.remove(&unrank(100).unwrap()));
+        assert!(matches!(set.containers[&0], Container::Bitmap { .. }));
+        assert_eq!(5_100, set.len());
+        assert_eq!(5_100, set.iter().count());
+        for n in 5_000..9_000 {
+            set.remove(&unrank(n).unwrap());
+        }
+        assert!(matches!(set.containers[&0], Container::Array(_)));
+        assert_eq!(range(0, 100).union(&range(9_000, 10_000)), set);
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = range(0, 6_000);
+        let b = range(5_000, 70_000);
+        let c = range(TOTAL_VALID - 10, TOTAL_VALID);
+        assert_eq!(range(0, 70_000), a.union(&b));
+        assert_eq!(range(5_000, 6_000), a.intersection(&b));
+        assert_eq!(range(5_000, 6_000), b.intersection(&a));
+        assert_eq!(range(0, 5_000), a.difference(&b));
+        assert_eq!(range(6_000, 70_000), b.difference(&a));
+        assert!(a.intersection(&c).is_empty());
+        assert_eq!(6_010, a.union(&c).len());
+    }
+
+    #[test]
+    fn snapshot_round_trip() {
+        let set = range(0, 5_000).union(&range(TOTAL_VALID - 10, TOTAL_VALID));
+        let mut bytes = Vec::new();
+        set.write_to(&mut bytes).unwrap();
+        assert_eq!(MAGIC, &bytes[..8]);
+        assert_eq!(set, SedolSet::read_from(bytes.as_slice()).unwrap());
+
+        let path = std::env::temp_dir().join(format!("sedol-set-{}.bin", std::process::id()));
+        set.save(&path).unwrap();
+        let loaded = SedolSet::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(set, loaded.unwrap());
+    }
+
+    #[test]
+    fn snapshot_invalid() {
+        let mut bytes = Vec::new();
+        range(0, 10).write_to(&mut bytes).unwrap();
+        let mut bad_version = bytes.clone();
+        bad_version[8] = 2;
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        for bad in [&bad_version[..], &bad_magic[..], &bytes[..bytes.len() - 1]] {
+            assert!(SedolSet::read_from(bad).is_err());
+        }
+        let mut out_of_range = Vec::new();
+        SedolSet::new().write_to(&mut out_of_range).unwrap();
+        out_of_range[10] = 1;
+        out_of_range.extend_from_slice(&u16::MAX.to_le_bytes());
+        out_of_range.extend_from_slice(&[0, 1, 0, 0, 0, 0, 0]);
+        let error = SedolSet::read_from(out_of_range.as_slice()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+    }
+}
@@ -0,0 +1,256 @@
+use std::fmt;
+
+use crate::{Sedol, SedolChar};
+
+/// The edit that turns the input into a suggested SEDOL, see [`suggest_corrections`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    /// The check digit was wrong
+    CheckDigit {
+        /// The check digit in the input
+        got: char,
+        /// The calculated check digit
+        expected: char,
+    },
+    /// The chars at `position` and `position + 1` were swapped
+    Transposition {
+        /// Zero-based char index of the first of the two chars
+        position: usize,
+    },
+    /// A char in the base was replaced by another
+    Substitution {
+        /// Zero-based char index of the char
+        position: usize,
+        /// The char in the input
+        from: char,
+        /// The char in the suggestion
+        to: char,
+    },
+    /// A char was typed twice, the duplicate at `position` is removed
+    Deletion {
+        /// Zero-based char index of the removed char in the input
+        position: usize,
+        /// The removed char
+        character: char,
+    },
+    /// A char was dropped, it is inserted at `position`
+    Insertion {
+        /// Zero-based char index of the inserted char in the suggestion
+        position: usize,
+        /// The inserted char
+        character: char,
+    },
+}
+
+impl fmt::Display for Edit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Edit::CheckDigit { got, expected } => {
+                write!(f, "check digit {} replaced by {}", got, expected)
+            }
+            Edit::Transposition { position } => {
+                write!(f, "chars at {} and {} swapped", position, position + 1)
+            }
+            Edit::Substitution { position, from, to } => {
+                write!(f, "{} at {} replaced by {}", from, position, to)
+            }
+            Edit::Deletion {
+                position,
+                character,
+            } => {
+                write!(f, "duplicate {} at {} removed", character, position)
+            }
+            Edit::Insertion {
+                position,
+                character,
+            } => {
+                write!(f, "{} inserted at {}", character, position)
+            }
+        }
+    }
+}
+
+/// A valid SEDOL suggested as a correction, with the edit that produced it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Correction {
+    /// The suggested SEDOL
+    pub sedol: Sedol,
+    /// The edit applied to the input
+    pub edit: Edit,
+}
+
+/// Suggest valid SEDOLs that are one typo away from the input.
+///
+/// Candidates are ranked by how likely the typo is, most likely first:
+/// 1. a wrong check digit
+/// 2. two adjacent chars swapped
+/// 3. a single char in the base replaced by another
+/// 4. a char typed twice (for 8 char input)
+/// 5. a char dropped (for 6 char input)
+///
+/// Each SEDOL is suggested at most once, for its most likely edit. Returns an empty `Vec` if
+/// the input is already valid.
+///
+/// ```
+/// let corrections = sedol::suggest_corrections("B15KXQ7");
+/// assert_eq!("B15KXQ8", corrections[0].sedol.as_str());
+///
+/// let corrections = sedol::suggest_corrections("B51KXQ8");
+/// assert!(corrections.iter().any(|c| c.sedol.as_str() == "B15KXQ8"));
+/// ```
+pub fn suggest_corrections(sedol: &str) -> Vec<Correction> {
+    let mut corrections: Vec<Correction> = Vec::new();
+    if sedol.parse::<Sedol>().is_ok() {
+        return corrections;
+    }
+    let chars: Vec<char> = sedol.chars().collect();
+    let mut push = |candidate: &[char], edit: Edit| {
+        let candidate: String = candidate.iter().collect();
+        if let Ok(sedol) = candidate.parse::<Sedol>() {
+            if !corrections.iter().any(|c| c.sedol == sedol) {
+                corrections.push(Correction { sedol, edit });
+            }
+        }
+    };
+
+    if chars.len() == 7 {
+        let mut candidate = chars.clone();
+        let expected = crate::calc_check_digit(sedol);
+        candidate[6] = expected;
+        push(
+            &candidate,
+            Edit::CheckDigit {
+                got: chars[6],
+                expected,
+            },
+        );
+
+        for position in 0..6 {
+            if chars[position] != chars[position + 1] {
+                let mut candidate = chars.clone();
+                candidate.swap(position, position + 1);
+                push(&candidate, Edit::Transposition { position });
+            }
+        }
+
+        for position in 0..6 {
+            for to in SedolChar::iter()
+                .map(char::from)
+                .filter(|&c| c != chars[position])
+            {
+                let mut candidate = chars.clone();
+                candidate[position] = to;
+                let from = chars[position];
+                push(&candidate, Edit::Substitution { position, from, to });
+            }
+        }
+    }
+
+    if chars.len() == 8 {
+        for position in 1..8 {
+            if chars[position] == chars[position - 1] {
+                let mut candidate = chars.clone();
+                let character = candidate.remove(position);
+                push(
+                    &candidate,
+                    Edit::Deletion {
+                        position,
+                        character,
+                    },
+                );
+            }
+        }
+    }
+
+    if chars.len() == 6 {
+        for position in 0..=6 {
+            for character in SedolChar::iter().map(char::from) {
+                let mut candidate = chars.clone();
+                candidate.insert(position, character);
+                push(
+                    &candidate,
+                    Edit::Insertion {
+                        position,
+                        character,
+                    },
+                );
+            }
+        }
+    }
+
+    corrections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_digit_first() {
+        let corrections = suggest_corrections("BD9MZZ6");
+        assert_eq!(
+            Correction {
+                sedol: "BD9MZZ7".parse().unwrap(),
+                edit: Edit::CheckDigit {
+                    got: '6',
+                    expected: '7'
+                }
+            },
+            corrections[0]
+        );
+        assert!(corrections[1..].iter().all(|c| matches!(
+            c.edit,
+            Edit::Transposition { .. } | Edit::Substitution { .. }
+        )));
+    }
+
+    #[test]
+    fn transposition() {
+        let corrections = suggest_corrections("B51KXQ8");
+        let transpositions: Vec<&Correction> = corrections
+            .iter()
+            .filter(|c| matches!(c.edit, Edit::Transposition { .. }))
+            .collect();
+        assert_eq!("B15KXQ8", transpositions[0].sedol.as_str());
+        assert_eq!(Edit::Transposition { position: 1 }, transpositions[0].edit);
+    }
+
+    #[test]
+    fn substitution() {
+        let corrections = suggest_corrections("B15KXR8");
+        assert!(corrections.contains(&Correction {
+            sedol: "B15KXQ8".parse().unwrap(),
+            edit: Edit::Substitution {
+                position: 5,
+                from: 'R',
+                to: 'Q'
+            }
+        }));
+    }
+
+    #[test]
+    fn duplicated_and_dropped() {
+        let corrections = suggest_corrections("B155KXQ8");
+        assert_eq!("B15KXQ8", corrections[0].sedol.as_str());
+        assert_eq!(
+            Edit::Deletion {
+                position: 3,
+                character: '5'
+            },
+            corrections[0].edit
+        );
+        let corrections = suggest_corrections("B1KXQ8");
+        assert!(corrections.iter().any(|c| c.sedol.as_str() == "B15KXQ8"
+            && c.edit
+                == Edit::Insertion {
+                    position: 2,
+                    character: '5'
+                }));
+    }
+
+    #[test]
+    fn valid_or_hopeless() {
+        assert!(suggest_corrections("B15KXQ8").is_empty());
+        assert!(suggest_corrections("??").is_empty());
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt::{self, Write};
+
+use crate::{char_value, check_digit_from_sum, try_calc_check_digit, SedolError, WEIGHTS};
+
+/// One character's contribution to the check digit, see [`CheckDigitTrace`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckDigitStep {
+    /// The character
+    pub character: char,
+    /// The value of the character: 0-9 for digits, 10-35 for letters A-Z
+    pub value: usize,
+    /// The weight for the character's position
+    pub weight: usize,
+    /// `value * weight`
+    pub product: usize,
+}
+
+/// Step-by-step explanation of a check digit calculation, as returned by [`explain_check_digit`].
+///
+/// `Display` renders it as a table. [`CheckDigitTrace::to_json`] gives a JSON object for
+/// inclusion in reports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckDigitTrace {
+    /// One step for each of the six base characters
+    pub steps: Vec<CheckDigitStep>,
+    /// Sum of the products
+    pub sum: usize,
+    /// `sum % 10`
+    pub remainder: usize,
+    /// The check digit, `(10 - remainder) % 10`
+    pub check_digit: char,
+}
+
+/// Explain how the check digit is calculated for a SEDOL or SEDOL base.
+///
+/// Accepts the same input as [`try_calc_check_digit`].
+///
+/// ```
+/// let trace = sedol::explain_check_digit("BD9MZZ").unwrap();
+/// assert_eq!(11, trace.steps[0].value);
+/// assert_eq!(633, trace.sum);
+/// assert_eq!('7', trace.check_digit);
+/// println!("{}", trace);
+/// ```
+pub fn explain_check_digit(sedol: &str) -> Result<CheckDigitTrace, SedolError> {
+    let check_digit = try_calc_check_digit(sedol)?;
+    let steps: Vec<CheckDigitStep> = WEIGHTS
+        .iter()
+        .zip(sedol.chars())
+        .map(|(&weight, character)| {
+            let value = char_value(character).unwrap_or(0);
+            CheckDigitStep {
+                character,
+                value,
+                weight,
+                product: value * weight,
+            }
+        })
+        .collect();
+    let sum = steps.iter().map(|step| step.product).sum();
+    debug_assert_eq!(check_digit, check_digit_from_sum(sum));
+    Ok(CheckDigitTrace {
+        steps,
+        sum,
+        remainder: sum % 10,
+        check_digit,
+    })
+}
+
+impl CheckDigitTrace {
+    /// Serialize the trace as a JSON object
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\"steps\":[");
+        for (i, step) in self.steps.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let _ = write!(
+                json,
+                "{{\"character\":\"{}\",\"value\":{},\"weight\":{},\"product\":{}}}",
+                step.character, step.value, step.weight, step.product
+            );
+        }
+        let _ = write!(
+            json,
+            "],\"sum\":{},\"remainder\":{},\"check_digit\":\"{}\"}}",
+            self.sum, self.remainder, self.check_digit
+        );
+        json
+    }
+}
+
+impl fmt::Display for CheckDigitTrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "char  value  weight  product")?;
+        for step in &self.steps {
+            writeln!(
+                f,
+                "{:<4}  {:>5}  {:>6}  {:>7}",
+                step.character, step.value, step.weight, step.product
+            )?;
+        }
+        writeln!(f, "sum                    {:>4}", self.sum)?;
+        writeln!(f, "sum % 10               {:>4}", self.remainder)?;
+        write!(
+            f,
+            "(10 - {}) % 10         {:>4}",
+            self.remainder, self.check_digit
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explain() {
+        let trace = explain_check_digit("B15KXQ8").unwrap();
+        let products: Vec<usize> = trace.steps.iter().map(|step| step.product).collect();
+        assert_eq!(vec![11, 3, 5, 140, 99, 234], products);
+        assert_eq!(492, trace.sum);
+        assert_eq!(2, trace.remainder);
+        assert_eq!('8', trace.check_digit);
+        assert!(explain_check_digit("B15KX").is_err());
+    }
+
+    #[test]
+    fn display() {
+        let trace = explain_check_digit("595413").unwrap();
+        let expected = "\
+char  value  weight  product
+5         5       1        5
+9         9       3       27
+5         5       1        5
+4         4       7       28
+1         1       3        3
+3         3       9       27
+sum                      95
+sum % 10                  5
+(10 - 5) % 10            5";
+        assert_eq!(expected, trace.to_string());
+    }
+
+    #[test]
+    fn json() {
+        let trace = explain_check_digit("595413").unwrap();
+        assert_eq!(
+            "{\"steps\":[\
+             {\"character\":\"5\",\"value\":5,\"weight\":1,\"product\":5},\
+             {\"character\":\"9\",\"value\":9,\"weight\":3,\"product\":27},\
+             {\"character\":\"5\",\"value\":5,\"weight\":1,\"product\":5},\
+             {\"character\":\"4\",\"value\":4,\"weight\":7,\"product\":28},\
+             {\"character\":\"1\",\"value\":1,\"weight\":3,\"product\":3},\
+             {\"character\":\"3\",\"value\":3,\"weight\":9,\"product\":27}],\
+             \"sum\":95,\"remainder\":5,\"check_digit\":\"5\"}",
+            trace.to_json()
+        );
+    }
+}
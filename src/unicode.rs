@@ -0,0 +1,254 @@
+use crate::{clean_with_report, CleanEdit, CleanOptions};
+
+/// A code point folded to ASCII by [`clean_unicode`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnicodeFold {
+    /// Zero-based char index in the input
+    pub position: usize,
+    /// The code point in the input
+    pub from: char,
+    /// The ASCII char it was folded to
+    pub to: char,
+}
+
+/// Fold a compatibility form or a curated lookalike to the ASCII letter or digit it stands for.
+///
+/// Covers:
+/// - full-width digits and Latin letters (U+FF10-U+FF5A) as produced by Asian-locale
+///   spreadsheets
+/// - Mathematical Alphanumeric Symbols (U+1D400-U+1D7FF), i.e. the bold, italic, script,
+///   double-struck and other styled Latin letters and digits, and the Letterlike Symbols such
+///   as `ℂ` and `ℎ` that stand in for the letters missing from that block
+/// - enclosed alphanumerics (U+2460-U+24FF) that stand for a single letter or digit, e.g.
+///   circled, parenthesized and full-stop forms
+/// - the Cyrillic and Greek capitals, and a few lowercase letters, that are indistinguishable
+///   from Latin ones in most fonts
+///
+/// Every mapping is one char to one char, so enclosed numbers from 10 up are not folded.
+/// Returns `None` for anything else, including ASCII.
+///
+/// ```
+/// assert_eq!(Some('B'), sedol::fold_to_ascii('Ｂ'));
+/// assert_eq!(Some('9'), sedol::fold_to_ascii('９'));
+/// assert_eq!(Some('9'), sedol::fold_to_ascii('𝟗')); // Mathematical bold digit nine
+/// assert_eq!(Some('4'), sedol::fold_to_ascii('④'));
+/// assert_eq!(Some('C'), sedol::fold_to_ascii('С')); // Cyrillic Es
+/// assert_eq!(None, sedol::fold_to_ascii('B'));
+/// ```
+pub fn fold_to_ascii(character: char) -> Option<char> {
+    let folded = match character {
+        '０'..='９' | 'Ａ'..='Ｚ' | 'ａ'..='ｚ' => {
+            return char::from_u32(character as u32 - 0xFEE0);
+        }
+        // Mathematical letters come in styles of 52, A-Z then a-z, and digits in styles of 10.
+        // The gaps in the letters are unassigned; Letterlike Symbols are used instead.
+        '\u{1D400}'..='\u{1D6A3}' if MATH_LETTER_GAPS.binary_search(&character).is_err() => {
+            return Some(nth_letter((character as u32 - 0x1D400) % 52));
+        }
+        '\u{1D7CE}'..='\u{1D7FF}' => return Some(nth_digit((character as u32 - 0x1D7CE) % 10)),
+        // Circled, parenthesized and full-stop digits one to nine, and circled zeros
+        '\u{2460}'..='\u{2468}' => return Some(nth_digit(character as u32 - 0x2460 + 1)),
+        '\u{2474}'..='\u{247C}' => return Some(nth_digit(character as u32 - 0x2474 + 1)),
+        '\u{2488}'..='\u{2490}' => return Some(nth_digit(character as u32 - 0x2488 + 1)),
+        '\u{24F5}'..='\u{24FD}' => return Some(nth_digit(character as u32 - 0x24F5 + 1)),
+        '\u{24EA}' | '\u{24FF}' => '0',
+        // Parenthesized small, circled capital and circled small letters
+        '\u{249C}'..='\u{24B5}' => return Some(nth_letter(character as u32 - 0x249C + 26)),
+        '\u{24B6}'..='\u{24E9}' => return Some(nth_letter(character as u32 - 0x24B6)),
+        // Cyrillic
+        'А' => 'A',
+        'В' => 'B',
+        'Е' => 'E',
+        'Ѕ' => 'S',
+        'І' => 'I',
+        'Ј' => 'J',
+        'К' => 'K',
+        'М' => 'M',
+        'Н' => 'H',
+        'О' => 'O',
+        'Р' => 'P',
+        'С' => 'C',
+        'Т' => 'T',
+        'Х' => 'X',
+        'У' => 'Y',
+        'а' => 'a',
+        'е' => 'e',
+        'о' => 'o',
+        'р' => 'p',
+        'с' => 'c',
+        'ѕ' => 's',
+        'і' => 'i',
+        'ј' => 'j',
+        'х' => 'x',
+        'у' => 'y',
+        // Letterlike Symbols in the gaps of the mathematical letters
+        'ℬ' => 'B',
+        'ℰ' => 'E',
+        'ℱ' => 'F',
+        'ℋ' => 'H',
+        'ℐ' => 'I',
+        'ℒ' => 'L',
+        'ℳ' => 'M',
+        'ℛ' => 'R',
+        'ℭ' => 'C',
+        'ℌ' => 'H',
+        'ℑ' => 'I',
+        'ℜ' => 'R',
+        'ℨ' => 'Z',
+        'ℂ' => 'C',
+        'ℍ' => 'H',
+        'ℕ' => 'N',
+        'ℙ' => 'P',
+        'ℚ' => 'Q',
+        'ℝ' => 'R',
+        'ℤ' => 'Z',
+        'ℎ' => 'h',
+        'ℯ' => 'e',
+        'ℊ' => 'g',
+        'ℴ' => 'o',
+        // Greek
+        'Α' => 'A',
+        'Β' => 'B',
+        'Ε' => 'E',
+        'Ζ' => 'Z',
+        'Η' => 'H',
+        'Ι' => 'I',
+        'Κ' => 'K',
+        'Μ' => 'M',
+        'Ν' => 'N',
+        'Ο' => 'O',
+        'Ρ' => 'P',
+        'Τ' => 'T',
+        'Υ' => 'Y',
+        'Χ' => 'X',
+        'ο' => 'o',
+        _ => return None,
+    };
+    Some(folded)
+}
+
+/// Code points in U+1D400-U+1D6A3 left unassigned because the letter is already encoded in
+/// Letterlike Symbols, e.g. U+1D53A for double-struck C, which is U+2102 `ℂ`
+const MATH_LETTER_GAPS: [char; 24] = [
+    '\u{1D455}',
+    '\u{1D49D}',
+    '\u{1D4A0}',
+    '\u{1D4A1}',
+    '\u{1D4A3}',
+    '\u{1D4A4}',
+    '\u{1D4A7}',
+    '\u{1D4A8}',
+    '\u{1D4AD}',
+    '\u{1D4BA}',
+    '\u{1D4BC}',
+    '\u{1D4C4}',
+    '\u{1D506}',
+    '\u{1D50B}',
+    '\u{1D50C}',
+    '\u{1D515}',
+    '\u{1D51D}',
+    '\u{1D53A}',
+    '\u{1D53F}',
+    '\u{1D545}',
+    '\u{1D547}',
+    '\u{1D548}',
+    '\u{1D549}',
+    '\u{1D551}',
+];
+
+/// The `n`th ASCII letter, counting A-Z as 0-25 and a-z as 26-51
+fn nth_letter(n: u32) -> char {
+    let base = if n < 26 { b'A' } else { b'a' };
+    (base + (n % 26) as u8) as char
+}
+
+/// The ASCII digit for `n`, which must be below 10
+fn nth_digit(n: u32) -> char {
+    (b'0' + n as u8) as char
+}
+
+/// Like [`clean`](crate::clean), but first fold compatibility forms and lookalikes to ASCII.
+///
+/// Chars are folded with [`fold_to_ascii`] and then every char that is not an ASCII letter
+/// or digit is removed. Returns the cleaned string and the folds that were made.
+///
+/// ```
+/// let (cleaned, folds) = sedol::clean_unicode("ＢＤ９-ＭＺＺ７");
+/// assert_eq!("BD9MZZ7", cleaned);
+/// assert_eq!(7, folds.len());
+/// assert_eq!(4, folds[3].position);
+/// ```
+pub fn clean_unicode(sedol: &str) -> (String, Vec<UnicodeFold>) {
+    let report = clean_with_report(sedol, CleanOptions::new().unicode(true));
+    let folds = report
+        .edits
+        .iter()
+        .filter_map(|edit| match *edit {
+            CleanEdit::Folded { position, from, to } => Some(UnicodeFold { position, from, to }),
+            _ => None,
+        })
+        .collect();
+    (report.cleaned.into_owned(), folds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate;
+
+    #[test]
+    fn full_width() {
+        let (cleaned, folds) = clean_unicode("ＢＤ９ＭＺＺ７");
+        assert_eq!("BD9MZZ7", validate(&cleaned).unwrap());
+        assert_eq!(
+            UnicodeFold {
+                position: 0,
+                from: 'Ｂ',
+                to: 'B'
+            },
+            folds[0]
+        );
+        assert_eq!("bd9", clean_unicode("ｂｄ９").0);
+    }
+
+    #[test]
+    fn compatibility_forms() {
+        let report = clean_with_report("𝟗④", CleanOptions::new().unicode(true));
+        assert_eq!("94", report.cleaned);
+        // Bold, script and monospace letters, circled capitals and double-struck digits
+        assert_eq!("BD9MZZ7", clean_unicode("𝐁𝒟𝟡𝙼ⓏⓏ⑦").0);
+        assert_eq!("bd0", clean_unicode("⒝ⓓ⓪").0);
+        assert_eq!(Some('z'), fold_to_ascii('\u{1D6A3}'));
+        // Double-struck and black-letter capitals from Letterlike Symbols, not the gaps
+        assert_eq!("BD9MZZ7", clean_unicode("𝔹𝔻𝟡𝕄ℤℤ𝟟").0);
+        assert_eq!("CHR", clean_unicode("ℂℋℜ").0);
+        for gap in MATH_LETTER_GAPS {
+            assert_eq!(None, fold_to_ascii(gap));
+        }
+        assert_eq!(None, fold_to_ascii('⑩'));
+    }
+
+    #[test]
+    fn lookalikes() {
+        // Cyrillic Ve and Greek Zeta
+        let (cleaned, folds) = clean_unicode("В15KXQ8 ΒD9MΖZ7");
+        assert_eq!("B15KXQ8BD9MZZ7", cleaned);
+        assert_eq!(
+            vec![0, 8, 12],
+            folds.iter().map(|f| f.position).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn folds_are_ascii_alphanumeric() {
+        for character in ('\u{80}'..='\u{1FFFF}').filter_map(|c| fold_to_ascii(c).map(|f| (c, f))) {
+            assert!(character.1.is_ascii_alphanumeric(), "{:?}", character);
+        }
+    }
+
+    #[test]
+    fn unchanged() {
+        assert_eq!(("BD9MZZ7".to_string(), vec![]), clean_unicode("BD-9MZ-Z7"));
+        assert_eq!(("".to_string(), vec![]), clean_unicode("ß→"));
+    }
+}
@@ -0,0 +1,314 @@
+use crate::{try_calc_check_digit, Sedol, SedolError, SedolFormat};
+
+/// Configurable SEDOL validation policy.
+///
+/// `Validator::new()` is as strict as [`validate`](crate::validate). Each option relaxes or
+/// tightens that policy, and [`Validator::validate`] returns the normalized [`Sedol`].
+///
+/// Normalization happens before validation, but a [`SedolError`] keeps the input as passed
+/// in and reports positions into it.
+///
+/// ```
+/// use sedol::Validator;
+///
+/// let validator = Validator::new()
+///     .lowercase(true)
+///     .trim(true)
+///     .strip_separators(true)
+///     .accept_base(true);
+/// assert_eq!("BD9MZZ7", validator.validate(" bd-9mz-z7 ").unwrap().as_str());
+/// assert_eq!("BD9MZZ7", validator.validate("BD9MZZ").unwrap().as_str());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Validator {
+    lowercase: bool,
+    trim: bool,
+    strip_separators: bool,
+    accept_base: bool,
+    reject_user_defined: bool,
+    allow_old_format: bool,
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        Validator {
+            lowercase: false,
+            trim: false,
+            strip_separators: false,
+            accept_base: false,
+            reject_user_defined: false,
+            allow_old_format: true,
+        }
+    }
+}
+
+impl Validator {
+    /// Create a validator with the same policy as [`validate`](crate::validate)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accept lowercase letters and uppercase them
+    pub fn lowercase(mut self, lowercase: bool) -> Self {
+        self.lowercase = lowercase;
+        self
+    }
+
+    /// Trim whitespace surrounding the SEDOL
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Strip separators (hyphens, spaces, dots and slashes), e.g. the UK DMO format `BD-9MZ-Z7`
+    pub fn strip_separators(mut self, strip_separators: bool) -> Self {
+        self.strip_separators = strip_separators;
+        self
+    }
+
+    /// Accept a 6 character base and append the calculated check digit
+    pub fn accept_base(mut self, accept_base: bool) -> Self {
+        self.accept_base = accept_base;
+        self
+    }
+
+    /// Reject SEDOLs in the user-defined range, i.e. those starting with 9
+    pub fn reject_user_defined(mut self, reject_user_defined: bool) -> Self {
+        self.reject_user_defined = reject_user_defined;
+        self
+    }
+
+    /// Allow old format SEDOLs, i.e. those containing only digits. Allowed by default.
+    pub fn allow_old_format(mut self, allow_old_format: bool) -> Self {
+        self.allow_old_format = allow_old_format;
+        self
+    }
+
+    /// Normalize the input according to this policy and validate it.
+    ///
+    /// A returned error keeps the input as passed in, and its position (if any) is the char
+    /// index of the offending char in that input. The length in
+    /// [`InvalidLength`](SedolError::InvalidLength) is that of the normalized value.
+    pub fn validate(&self, sedol: &str) -> Result<Sedol, SedolError> {
+        let chars: Vec<char> = sedol.chars().collect();
+        let (mut start, mut end) = (0, chars.len());
+        if self.trim {
+            while start < end && chars[start].is_whitespace() {
+                start += 1;
+            }
+            while end > start && chars[end - 1].is_whitespace() {
+                end -= 1;
+            }
+        }
+        // `positions[i]` is the index in `chars` of the i-th char of the normalized value
+        let mut normalized = String::with_capacity(end - start);
+        let mut positions = Vec::with_capacity(end - start);
+        for (position, &character) in chars.iter().enumerate().take(end).skip(start) {
+            if self.strip_separators && matches!(character, '-' | ' ' | '.' | '/') {
+                continue;
+            }
+            normalized.push(if self.lowercase {
+                character.to_ascii_uppercase()
+            } else {
+                character
+            });
+            positions.push(position);
+        }
+        self.validate_normalized(normalized)
+            .map_err(|error| with_raw_input(error, sedol, &chars, &positions))
+    }
+
+    fn validate_normalized(&self, mut sedol: String) -> Result<Sedol, SedolError> {
+        if self.accept_base && sedol.chars().count() == 6 {
+            let check_digit = try_calc_check_digit(&sedol)?;
+            sedol.push(check_digit);
+        }
+        let sedol: Sedol = sedol.parse()?;
+        if self.reject_user_defined && sedol.format() == SedolFormat::UserDefined {
+            return Err(SedolError::UserDefined {
+                position: 0,
+                input: sedol.to_string(),
+            });
+        }
+        if !self.allow_old_format && sedol.format() != SedolFormat::Modern {
+            return Err(SedolError::OldFormatNotAllowed {
+                input: sedol.to_string(),
+            });
+        }
+        Ok(sedol)
+    }
+}
+
+/// Point an error raised on the normalized value back at the raw input
+fn with_raw_input(error: SedolError, raw: &str, chars: &[char], positions: &[usize]) -> SedolError {
+    let input = raw.to_string();
+    // A check digit appended by `accept_base` is not in the input, so point past its end
+    let raw_position = |position: usize| positions.get(position).copied().unwrap_or(chars.len());
+    match error {
+        SedolError::InvalidCharacter {
+            character,
+            position,
+            ..
+        } => SedolError::InvalidCharacter {
+            character: chars
+                .get(raw_position(position))
+                .copied()
+                .unwrap_or(character),
+            position: raw_position(position),
+            input,
+        },
+        SedolError::InvalidOldFormat {
+            character,
+            position,
+            ..
+        } => SedolError::InvalidOldFormat {
+            character: chars
+                .get(raw_position(position))
+                .copied()
+                .unwrap_or(character),
+            position: raw_position(position),
+            input,
+        },
+        SedolError::InvalidLength { length, .. } => SedolError::InvalidLength { length, input },
+        SedolError::InvalidCheckDigit {
+            got_check_digit,
+            calc_check_digit,
+            position,
+            ..
+        } => SedolError::InvalidCheckDigit {
+            got_check_digit,
+            calc_check_digit,
+            position: raw_position(position),
+            input,
+        },
+        SedolError::UserDefined { position, .. } => SedolError::UserDefined {
+            position: raw_position(position),
+            input,
+        },
+        SedolError::OldFormatNotAllowed { .. } => SedolError::OldFormatNotAllowed { input },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_strict() {
+        let validator = Validator::new();
+        assert_eq!("B15KXQ8", validator.validate("B15KXQ8").unwrap().as_str());
+        assert_eq!(
+            Err(SedolError::InvalidCharacter {
+                character: 'b',
+                position: 0,
+                input: "b15kxq8".to_string()
+            }),
+            validator.validate("b15kxq8")
+        );
+        assert_eq!(
+            Err(SedolError::InvalidLength {
+                length: 6,
+                input: "B15KXQ".to_string()
+            }),
+            validator.validate("B15KXQ")
+        );
+    }
+
+    #[test]
+    fn lenient() {
+        let validator = Validator::new()
+            .lowercase(true)
+            .trim(true)
+            .strip_separators(true)
+            .accept_base(true);
+        assert_eq!(
+            "BD9MZZ7",
+            validator.validate("\tbd-9mz-z7\n").unwrap().as_str()
+        );
+        assert_eq!("BD9MZZ7", validator.validate("BD 9MZ Z").unwrap().as_str());
+        assert_eq!(
+            Err(SedolError::InvalidCharacter {
+                character: 'a',
+                position: 0,
+                input: "a15kxq".to_string()
+            }),
+            validator.validate("a15kxq")
+        );
+    }
+
+    #[test]
+    fn errors_keep_raw_input() {
+        let validator = Validator::new()
+            .lowercase(true)
+            .trim(true)
+            .strip_separators(true);
+        assert_eq!(
+            Err(SedolError::InvalidCheckDigit {
+                got_check_digit: '6',
+                calc_check_digit: '7',
+                position: 9,
+                input: " bd-9mz-z6 ".to_string()
+            }),
+            validator.validate(" bd-9mz-z6 ")
+        );
+        assert_eq!(
+            Err(SedolError::InvalidCharacter {
+                character: 'e',
+                position: 6,
+                input: " bd-9me-z7".to_string()
+            }),
+            validator.validate(" bd-9me-z7")
+        );
+        assert_eq!(
+            Err(SedolError::InvalidOldFormat {
+                character: 'D',
+                position: 4,
+                input: "  0-D9MZZ6".to_string()
+            }),
+            validator.validate("  0-D9MZZ6")
+        );
+        for (validator, input) in [
+            (validator, " bd-9mz-z6 "),
+            (validator, " bd-9me-z7"),
+            (validator.reject_user_defined(true), "  912-345-8"),
+        ] {
+            let error = validator.validate(input).unwrap_err();
+            let character = error
+                .position()
+                .and_then(|position| error.input().chars().nth(position));
+            let expected = match error {
+                SedolError::InvalidCheckDigit { .. } => '6',
+                SedolError::InvalidCharacter { .. } => 'e',
+                _ => '9',
+            };
+            assert_eq!(Some(expected), character, "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn reject_user_defined() {
+        let validator = Validator::new().reject_user_defined(true);
+        let user_defined = format!("912345{}", crate::calc_check_digit("912345"));
+        assert!(Validator::new().validate(&user_defined).is_ok());
+        assert_eq!(
+            Err(SedolError::UserDefined {
+                position: 0,
+                input: user_defined.clone()
+            }),
+            validator.validate(&user_defined)
+        );
+        assert!(validator.validate("5954135").is_ok());
+    }
+
+    #[test]
+    fn forbid_old_format() {
+        let validator = Validator::new().allow_old_format(false);
+        assert_eq!(
+            Err(SedolError::OldFormatNotAllowed {
+                input: "5954135".to_string()
+            }),
+            validator.validate("5954135")
+        );
+        assert!(validator.validate("B15KXQ8").is_ok());
+    }
+}